@@ -1,6 +1,6 @@
 use self::ChoseongCharacter::*;
 use crate::constants::*;
-use crate::CharacterInformation;
+use crate::{CharacterInformation, JongseongCharacter};
 
 #[derive(Clone, Debug, PartialEq, PartialOrd)]
 pub enum ChoseongCharacter {
@@ -68,6 +68,43 @@ impl ChoseongCharacter {
             Hieuh => 18,
         }
     }
+
+    /// Doubles a consonant, e.g. ㄱ + ㄱ → ㄲ.
+    pub fn combine(&self, other: &Self) -> Option<Self> {
+        let character = match (self, other) {
+            (Giyeok, Giyeok) => SsangGiyeok,
+            (Digeut, Digeut) => SsangDigeut,
+            (Bieup, Bieup) => SsangBieup,
+            (Siot, Siot) => SsangSiot,
+            (Jieut, Jieut) => SsangJieut,
+            _ => return None,
+        };
+        Some(character)
+    }
+
+    /// The same consonant as a final. ㄸ, ㅃ and ㅉ cannot be finals.
+    pub fn to_jongseong(&self) -> Option<JongseongCharacter> {
+        let character = match self {
+            Giyeok => JongseongCharacter::Giyeok,
+            SsangGiyeok => JongseongCharacter::SsangGiyeok,
+            Nieun => JongseongCharacter::Nieun,
+            Digeut => JongseongCharacter::Digeut,
+            Rieul => JongseongCharacter::Rieul,
+            Mieum => JongseongCharacter::Mieum,
+            Bieup => JongseongCharacter::Bieup,
+            Siot => JongseongCharacter::Siot,
+            SsangSiot => JongseongCharacter::SsangSiot,
+            Ieung => JongseongCharacter::Ieung,
+            Jieut => JongseongCharacter::Jieut,
+            Chieut => JongseongCharacter::Chieut,
+            Kiyeok => JongseongCharacter::Kieuk,
+            Tieut => JongseongCharacter::Tieut,
+            Pieup => JongseongCharacter::Pieup,
+            Hieuh => JongseongCharacter::Hieuh,
+            SsangDigeut | SsangBieup | SsangJieut => return None,
+        };
+        Some(character)
+    }
 }
 
 impl CharacterInformation for ChoseongCharacter {
@@ -179,4 +216,19 @@ mod tests {
         assert_eq!(Pieup.to_composable(), 9996);
         assert_eq!(Hieuh.to_composable(), 10584);
     }
+
+    #[test]
+    fn to_jongseong() {
+        assert_eq!(Giyeok.to_jongseong(), Some(JongseongCharacter::Giyeok));
+        assert_eq!(Kiyeok.to_jongseong(), Some(JongseongCharacter::Kieuk));
+        assert_eq!(SsangDigeut.to_jongseong(), None);
+    }
+
+    #[test]
+    fn combine() {
+        assert_eq!(Giyeok.combine(&Giyeok), Some(SsangGiyeok));
+        assert_eq!(Jieut.combine(&Jieut), Some(SsangJieut));
+        assert_eq!(Nieun.combine(&Nieun), None);
+        assert_eq!(Giyeok.combine(&Siot), None);
+    }
 }
@@ -1,5 +1,5 @@
 use self::JongseongCharacter::*;
-use crate::CharacterInformation;
+use crate::{CharacterInformation, ChoseongCharacter};
 
 /// See:
 /// * https://en.wikipedia.org/wiki/Hangul_Jamo_(Unicode_block)
@@ -93,6 +93,68 @@ impl JongseongCharacter {
             Hieuh => 27,
         }
     }
+
+    /// The same consonant as an initial. Compound finals have no initial.
+    pub fn to_choseong(&self) -> Option<ChoseongCharacter> {
+        let character = match self {
+            Giyeok => ChoseongCharacter::Giyeok,
+            SsangGiyeok => ChoseongCharacter::SsangGiyeok,
+            Nieun => ChoseongCharacter::Nieun,
+            Digeut => ChoseongCharacter::Digeut,
+            Rieul => ChoseongCharacter::Rieul,
+            Mieum => ChoseongCharacter::Mieum,
+            Bieup => ChoseongCharacter::Bieup,
+            Siot => ChoseongCharacter::Siot,
+            SsangSiot => ChoseongCharacter::SsangSiot,
+            Ieung => ChoseongCharacter::Ieung,
+            Jieut => ChoseongCharacter::Jieut,
+            Chieut => ChoseongCharacter::Chieut,
+            Kieuk => ChoseongCharacter::Kiyeok,
+            Tieut => ChoseongCharacter::Tieut,
+            Pieup => ChoseongCharacter::Pieup,
+            Hieuh => ChoseongCharacter::Hieuh,
+            _ => return None,
+        };
+        Some(character)
+    }
+
+    /// Combines two finals into a compound final, e.g. ㄹ + ㄱ → ㄺ.
+    pub fn combine(&self, other: &Self) -> Option<Self> {
+        let character = match (self, other) {
+            (Giyeok, Siot) => GiyeokSiot,
+            (Nieun, Jieut) => NieunJieut,
+            (Nieun, Hieuh) => NieunHieuh,
+            (Rieul, Giyeok) => RieulGiyeok,
+            (Rieul, Mieum) => RieulMieum,
+            (Rieul, Bieup) => RieulBieup,
+            (Rieul, Siot) => RieulSiot,
+            (Rieul, Tieut) => RieulTieut,
+            (Rieul, Pieup) => RieulPieup,
+            (Rieul, Hieuh) => RieulHieuh,
+            (Bieup, Siot) => BieupSiot,
+            _ => return None,
+        };
+        Some(character)
+    }
+
+    /// Splits a compound final, e.g. ㄺ → ㄹ + ㄱ.
+    pub fn split(&self) -> Option<(Self, Self)> {
+        let characters = match self {
+            GiyeokSiot => (Giyeok, Siot),
+            NieunJieut => (Nieun, Jieut),
+            NieunHieuh => (Nieun, Hieuh),
+            RieulGiyeok => (Rieul, Giyeok),
+            RieulMieum => (Rieul, Mieum),
+            RieulBieup => (Rieul, Bieup),
+            RieulSiot => (Rieul, Siot),
+            RieulTieut => (Rieul, Tieut),
+            RieulPieup => (Rieul, Pieup),
+            RieulHieuh => (Rieul, Hieuh),
+            BieupSiot => (Bieup, Siot),
+            _ => return None,
+        };
+        Some(characters)
+    }
 }
 
 impl CharacterInformation for JongseongCharacter {
@@ -236,4 +298,26 @@ mod tests {
         assert_eq!(Pieup.to_composable(), 26);
         assert_eq!(Hieuh.to_composable(), 27);
     }
+
+    #[test]
+    fn to_choseong() {
+        assert_eq!(Giyeok.to_choseong(), Some(ChoseongCharacter::Giyeok));
+        assert_eq!(Kieuk.to_choseong(), Some(ChoseongCharacter::Kiyeok));
+        assert_eq!(SsangSiot.to_choseong(), Some(ChoseongCharacter::SsangSiot));
+        assert_eq!(RieulGiyeok.to_choseong(), None);
+    }
+
+    #[test]
+    fn combine() {
+        assert_eq!(Rieul.combine(&Giyeok), Some(RieulGiyeok));
+        assert_eq!(Bieup.combine(&Siot), Some(BieupSiot));
+        assert_eq!(Giyeok.combine(&Giyeok), None);
+    }
+
+    #[test]
+    fn split() {
+        assert_eq!(RieulGiyeok.split(), Some((Rieul, Giyeok)));
+        assert_eq!(NieunHieuh.split(), Some((Nieun, Hieuh)));
+        assert_eq!(SsangGiyeok.split(), None);
+    }
 }
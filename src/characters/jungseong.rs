@@ -74,6 +74,36 @@ impl JungseongCharacter {
             I => 20,
         }
     }
+
+    /// Combines two vowels into a compound vowel, e.g. ㅗ + ㅏ → ㅘ.
+    pub fn combine(&self, other: &Self) -> Option<Self> {
+        let character = match (self, other) {
+            (O, A) => WA,
+            (O, AE) => WAE,
+            (O, I) => OE,
+            (U, EO) => WEO,
+            (U, E) => WE,
+            (U, I) => WI,
+            (EU, I) => YI,
+            _ => return None,
+        };
+        Some(character)
+    }
+
+    /// Splits a compound vowel, e.g. ㅘ → ㅗ + ㅏ.
+    pub fn split(&self) -> Option<(Self, Self)> {
+        let characters = match self {
+            WA => (O, A),
+            WAE => (O, AE),
+            OE => (O, I),
+            WEO => (U, EO),
+            WE => (U, E),
+            WI => (U, I),
+            YI => (EU, I),
+            _ => return None,
+        };
+        Some(characters)
+    }
 }
 
 impl CharacterInformation for JungseongCharacter {
@@ -86,7 +116,7 @@ impl CharacterInformation for JungseongCharacter {
     }
 
     fn to_composable(&self) -> u32 {
-        self.to_index() * JUNGSEONG_COUNT
+        self.to_index() * JONGSEONG_COUNT
     }
 }
 
@@ -172,25 +202,40 @@ mod tests {
     #[test]
     fn to_composable() {
         assert_eq!(A.to_composable(), 0);
-        assert_eq!(AE.to_composable(), 21);
-        assert_eq!(YA.to_composable(), 42);
-        assert_eq!(YAE.to_composable(), 63);
-        assert_eq!(EO.to_composable(), 84);
-        assert_eq!(E.to_composable(), 105);
-        assert_eq!(YEO.to_composable(), 126);
-        assert_eq!(YE.to_composable(), 147);
-        assert_eq!(O.to_composable(), 168);
-        assert_eq!(WA.to_composable(), 189);
-        assert_eq!(WAE.to_composable(), 210);
-        assert_eq!(OE.to_composable(), 231);
-        assert_eq!(YO.to_composable(), 252);
-        assert_eq!(U.to_composable(), 273);
-        assert_eq!(WEO.to_composable(), 294);
-        assert_eq!(WE.to_composable(), 315);
-        assert_eq!(WI.to_composable(), 336);
-        assert_eq!(YU.to_composable(), 357);
-        assert_eq!(EU.to_composable(), 378);
-        assert_eq!(YI.to_composable(), 399);
-        assert_eq!(I.to_composable(), 420);
+        assert_eq!(AE.to_composable(), 28);
+        assert_eq!(YA.to_composable(), 56);
+        assert_eq!(YAE.to_composable(), 84);
+        assert_eq!(EO.to_composable(), 112);
+        assert_eq!(E.to_composable(), 140);
+        assert_eq!(YEO.to_composable(), 168);
+        assert_eq!(YE.to_composable(), 196);
+        assert_eq!(O.to_composable(), 224);
+        assert_eq!(WA.to_composable(), 252);
+        assert_eq!(WAE.to_composable(), 280);
+        assert_eq!(OE.to_composable(), 308);
+        assert_eq!(YO.to_composable(), 336);
+        assert_eq!(U.to_composable(), 364);
+        assert_eq!(WEO.to_composable(), 392);
+        assert_eq!(WE.to_composable(), 420);
+        assert_eq!(WI.to_composable(), 448);
+        assert_eq!(YU.to_composable(), 476);
+        assert_eq!(EU.to_composable(), 504);
+        assert_eq!(YI.to_composable(), 532);
+        assert_eq!(I.to_composable(), 560);
+    }
+
+    #[test]
+    fn combine() {
+        assert_eq!(O.combine(&A), Some(WA));
+        assert_eq!(U.combine(&I), Some(WI));
+        assert_eq!(EU.combine(&I), Some(YI));
+        assert_eq!(A.combine(&I), None);
+    }
+
+    #[test]
+    fn split() {
+        assert_eq!(WAE.split(), Some((O, AE)));
+        assert_eq!(WE.split(), Some((U, E)));
+        assert_eq!(AE.split(), None);
     }
 }
@@ -1,5 +1,6 @@
 use crate::characters::{ChoseongCharacter::*, *};
 use crate::constants::*;
+use crate::error::*;
 use crate::syllable::*;
 use std::convert::TryFrom;
 
@@ -117,7 +118,7 @@ impl From<ChoseongCharacter> for Choseong {
 }
 
 impl TryFrom<u32> for Choseong {
-    type Error = ();
+    type Error = HangulError;
 
     fn try_from(item: u32) -> Result<Self, Self::Error> {
         let character = match to_code(item) {
@@ -155,12 +156,12 @@ impl TryFrom<u32> for Choseong {
             0x3147 => Choseong::Compat(Ieung),
             0x3148 => Choseong::Compat(Jieut),
             0x3149 => Choseong::Compat(SsangJieut),
-            0x313A => Choseong::Compat(Chieut),
+            0x314A => Choseong::Compat(Chieut),
             0x314B => Choseong::Compat(Kiyeok),
             0x314C => Choseong::Compat(Tieut),
             0x314D => Choseong::Compat(Pieup),
             0x314E => Choseong::Compat(Hieuh),
-            _ => return Err(()),
+            _ => return Err(HangulError::new(item, HangulCategory::Choseong)),
         };
 
         Ok(character)
@@ -206,7 +207,7 @@ impl From<Choseong> for u32 {
                 Ieung => 0x3147,
                 Jieut => 0x3148,
                 SsangJieut => 0x3149,
-                Chieut => 0x313A,
+                Chieut => 0x314A,
                 Kiyeok => 0x314B,
                 Tieut => 0x314C,
                 Pieup => 0x314D,
@@ -217,7 +218,7 @@ impl From<Choseong> for u32 {
 }
 
 impl TryFrom<char> for Choseong {
-    type Error = ();
+    type Error = HangulError;
 
     fn try_from(item: char) -> Result<Self, Self::Error> {
         Choseong::try_from(item as u32)
@@ -263,7 +264,7 @@ impl From<Choseong> for char {
                 Ieung => '\u{3147}',
                 Jieut => '\u{3148}',
                 SsangJieut => '\u{3149}',
-                Chieut => '\u{313A}',
+                Chieut => '\u{314A}',
                 Kiyeok => '\u{314B}',
                 Tieut => '\u{314C}',
                 Pieup => '\u{314D}',
@@ -311,7 +312,7 @@ mod tests {
     const NON_COMPAT_CHOSEONG_END_U32: u32 = 0x314F;
     const COMPAT_CHOSEONG_U32_LIST: [u32; 19] = [
         0x3131, 0x3132, 0x3134, 0x3137, 0x3138, 0x3139, 0x3141, 0x3142, 0x3143, 0x3145, 0x3146,
-        0x3147, 0x3148, 0x3149, 0x313A, 0x314B, 0x314C, 0x314D, 0x314E,
+        0x3147, 0x3148, 0x3149, 0x314A, 0x314B, 0x314C, 0x314D, 0x314E,
     ];
 
     const NON_NORMAL_CHOSEONG_START_CHAR: char = '\u{1099}';
@@ -324,7 +325,7 @@ mod tests {
     const NON_COMPAT_CHOSEONG_START_CHAR: char = '\u{3130}';
     const NON_COMPAT_CHOSEONG_END_CHAR: char = '\u{314F}';
     const COMPAT_CHOSEONG_CHAR_LIST: [char; 19] = [
-        'ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ', 'ㄺ',
+        'ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ', 'ㅊ',
         'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ',
     ];
 
@@ -417,4 +418,12 @@ mod tests {
         }
         assert_eq!(NON_COMPAT_CHOSEONG_END_CHAR.is_compat_choseong(), false);
     }
+
+    #[test]
+    fn compat_chieut() {
+        assert_eq!(Choseong::try_from('ㅊ'), Ok(Choseong::Compat(Chieut)));
+        assert_eq!(u32::from(Chieut.to_compat()), 0x314A);
+        assert_eq!(char::from(Chieut.to_compat()), 'ㅊ');
+        assert!(Choseong::try_from('ㄺ').is_err());
+    }
 }
@@ -0,0 +1,143 @@
+use crate::choseong::*;
+use crate::jongseong::*;
+use crate::jungseong::*;
+use crate::syllable::*;
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+
+#[derive(Clone, Debug)]
+pub struct Compose<I: Iterator<Item = char>> {
+    characters: I,
+    lookahead: VecDeque<char>,
+}
+
+pub fn compose<I: IntoIterator<Item = char>>(characters: I) -> Compose<I::IntoIter> {
+    Compose {
+        characters: characters.into_iter(),
+        lookahead: VecDeque::with_capacity(3),
+    }
+}
+
+fn to_choseong(character: char) -> Option<Choseong> {
+    if character.is_jamo() {
+        Choseong::try_from(character).ok()
+    } else {
+        None
+    }
+}
+
+fn to_jungseong(character: char) -> Option<Jungseong> {
+    if character.is_jamo() {
+        Jungseong::try_from(character).ok()
+    } else {
+        None
+    }
+}
+
+fn to_jongseong(character: char) -> Option<Jongseong> {
+    if character.is_jamo() {
+        Jongseong::try_from(character).ok()
+    } else {
+        None
+    }
+}
+
+impl<I: Iterator<Item = char>> Compose<I> {
+    fn peek(&mut self, index: usize) -> Option<char> {
+        while self.lookahead.len() <= index {
+            let character = self.characters.next()?;
+            self.lookahead.push_back(character);
+        }
+
+        self.lookahead.get(index).copied()
+    }
+
+    fn advance(&mut self) -> Option<char> {
+        self.peek(0)?;
+        self.lookahead.pop_front()
+    }
+}
+
+impl<I: Iterator<Item = char>> Iterator for Compose<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let character = self.advance()?;
+        let choseong = match to_choseong(character) {
+            Some(choseong) => choseong,
+            None => return Some(character),
+        };
+        let jungseong = match self.peek(0).and_then(to_jungseong) {
+            Some(jungseong) => jungseong,
+            None => return Some(character),
+        };
+        self.advance();
+
+        // A compat consonant followed by a vowel starts the next syllable instead.
+        let starts_syllable = self.peek(0).and_then(to_choseong).is_some()
+            && self.peek(1).and_then(to_jungseong).is_some();
+        let jongseong = if starts_syllable {
+            None
+        } else {
+            self.peek(0).and_then(to_jongseong)
+        };
+        if jongseong.is_some() {
+            self.advance();
+        }
+
+        match Syllable::try_from((choseong, jungseong, jongseong)) {
+            Ok(syllable) => Some(syllable.into()),
+            Err(_) => unreachable!(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decompose::*;
+
+    #[test]
+    fn compose_compat_jamo() {
+        assert_eq!(compose("ㅎㅏㄴㄱㅡㄹ".chars()).collect::<String>(), "한글");
+        assert_eq!(compose("ㅇㅏㄴㄴㅕㅇ".chars()).collect::<String>(), "안녕");
+        assert_eq!(compose("ㄷㅏㄺ".chars()).collect::<String>(), "닭");
+        assert_eq!(compose("ㄱㅐㄱㄱㅒ".chars()).collect::<String>(), "객걔");
+    }
+
+    #[test]
+    fn compose_prefers_choseong_before_vowel() {
+        assert_eq!(compose("ㄱㅏㄴㅏ".chars()).collect::<String>(), "가나");
+        assert_eq!(compose("ㅇㅓㄳㅣ".chars()).collect::<String>(), "얷ㅣ");
+    }
+
+    #[test]
+    fn compose_normal_jamo() {
+        assert_eq!(
+            compose("\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}".chars()).collect::<String>(),
+            "한글"
+        );
+        assert_eq!(
+            compose("\u{1100}\u{1161}\u{11A8}\u{1161}".chars()).collect::<String>(),
+            "각\u{1161}"
+        );
+    }
+
+    #[test]
+    fn compose_leaves_orphans() {
+        assert_eq!(compose("ㄱㄴㄷ".chars()).collect::<String>(), "ㄱㄴㄷ");
+        assert_eq!(compose("ㅏㄱ".chars()).collect::<String>(), "ㅏㄱ");
+        assert_eq!(
+            compose("abc ㅎㅏ 가ㅏ".chars()).collect::<String>(),
+            "abc 하 가ㅏ"
+        );
+        assert_eq!(compose("ㄱ".chars()).collect::<String>(), "ㄱ");
+    }
+
+    #[test]
+    fn compose_inverts_decompose() {
+        let text = "다람쥐 헌 쳇바퀴에 타고파, 뛩!";
+        assert_eq!(compose(decompose(text)).collect::<String>(), text);
+        assert_eq!(compose(decompose_compat(text)).collect::<String>(), text);
+    }
+}
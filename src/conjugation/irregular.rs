@@ -0,0 +1,303 @@
+use super::*;
+
+/// The irregular conjugations (불규칙 활용), named after the part of the stem that alternates.
+///
+/// See:
+/// * https://en.wikipedia.org/wiki/Korean_verbs#Irregular_verbs
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Irregular {
+    /// ㅂ becomes 우, or 오 in 돕다 and 곱다, e.g. 춥다 → 추워.
+    Bieup,
+    /// ㄷ becomes ㄹ, e.g. 듣다 → 들어.
+    Digeut,
+    /// ㅅ drops without contraction, e.g. 짓다 → 지어.
+    Siot,
+    /// ㅎ drops and the vowel merges with 아/어, e.g. 파랗다 → 파래.
+    Hieuh,
+    /// 르 becomes ㄹ + ㄹ, e.g. 부르다 → 불러.
+    Reu,
+    /// 어 becomes 러, e.g. 이르다 → 이르러.
+    Reo,
+    /// ㅜ drops, e.g. 푸다 → 퍼.
+    U,
+    /// ㅡ drops, e.g. 쓰다 → 써.
+    Eu,
+}
+
+/// Stems that alternate, matched against the end of the stem so that 알아듣다 follows 듣다.
+const LEXICON: &[(&str, Irregular)] = &[
+    ("돕", Irregular::Bieup),
+    ("곱", Irregular::Bieup),
+    ("눕", Irregular::Bieup),
+    ("줍", Irregular::Bieup),
+    ("굽", Irregular::Bieup),
+    ("깁", Irregular::Bieup),
+    ("춥", Irregular::Bieup),
+    ("덥", Irregular::Bieup),
+    ("쉽", Irregular::Bieup),
+    ("맵", Irregular::Bieup),
+    ("밉", Irregular::Bieup),
+    ("어렵", Irregular::Bieup),
+    ("가깝", Irregular::Bieup),
+    ("무겁", Irregular::Bieup),
+    ("가볍", Irregular::Bieup),
+    ("귀엽", Irregular::Bieup),
+    ("고맙", Irregular::Bieup),
+    ("반갑", Irregular::Bieup),
+    ("즐겁", Irregular::Bieup),
+    ("뜨겁", Irregular::Bieup),
+    ("차갑", Irregular::Bieup),
+    ("두껍", Irregular::Bieup),
+    ("싱겁", Irregular::Bieup),
+    ("아깝", Irregular::Bieup),
+    ("어둡", Irregular::Bieup),
+    ("무섭", Irregular::Bieup),
+    ("놀랍", Irregular::Bieup),
+    ("그립", Irregular::Bieup),
+    ("가렵", Irregular::Bieup),
+    ("우습", Irregular::Bieup),
+    ("사납", Irregular::Bieup),
+    ("듣", Irregular::Digeut),
+    ("걷", Irregular::Digeut),
+    ("묻", Irregular::Digeut),
+    ("싣", Irregular::Digeut),
+    ("깨닫", Irregular::Digeut),
+    ("일컫", Irregular::Digeut),
+    ("긷", Irregular::Digeut),
+    ("붇", Irregular::Digeut),
+    ("짓", Irregular::Siot),
+    ("낫", Irregular::Siot),
+    ("붓", Irregular::Siot),
+    ("긋", Irregular::Siot),
+    ("잇", Irregular::Siot),
+    ("젓", Irregular::Siot),
+    ("이르", Irregular::Reo),
+    ("푸르", Irregular::Reo),
+    ("푸", Irregular::U),
+    ("따르", Irregular::Eu),
+    ("치르", Irregular::Eu),
+    ("들르", Irregular::Eu),
+    ("다다르", Irregular::Eu),
+    ("우러르", Irregular::Eu),
+];
+
+/// Stems in ㅎ that conjugate regularly.
+const REGULAR_HIEUH: &[&str] = &["좋", "놓", "넣", "낳", "닿", "쌓", "찧", "땋", "빻"];
+
+/// Stems in ㅎ that come from -하다 and contract to ㅐ, e.g. 그렇다 → 그래.
+const HADA_HIEUH: &[&str] = &["이렇", "그렇", "저렇", "어떻"];
+
+/// Classifies the stem of a dictionary form as irregular, e.g. 돕다 → `Irregular::Bieup`.
+pub(crate) fn classify_stem(stem: &[Jamo]) -> Option<Irregular> {
+    let text = to_text(stem);
+    if let Some((_, irregular)) = LEXICON.iter().find(|(entry, _)| text.ends_with(entry)) {
+        return Some(*irregular);
+    }
+
+    let (choseong, jungseong, jongseong) = stem.last()?;
+    match jongseong {
+        Some(JongseongCharacter::Bieup)
+            if stem.len() > 1
+                && ADJECTIVE_SUFFIXES
+                    .iter()
+                    .any(|suffix| text.ends_with(suffix)) =>
+        {
+            Some(Irregular::Bieup)
+        }
+        Some(JongseongCharacter::Hieuh)
+            if !REGULAR_HIEUH.iter().any(|entry| text.ends_with(entry)) =>
+        {
+            Some(Irregular::Hieuh)
+        }
+        None if *jungseong == JungseongCharacter::EU => {
+            if *choseong == ChoseongCharacter::Rieul && stem.len() > 1 {
+                Some(Irregular::Reu)
+            } else {
+                Some(Irregular::Eu)
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Classifies a dictionary form as irregular, e.g. 돕다 → `Irregular::Bieup`.
+///
+/// Returns `None` for regular predicates and for text that is not a dictionary form.
+pub fn classify_irregular(predicate: &str) -> Option<Irregular> {
+    classify_stem(&stem(predicate)?)
+}
+
+/// Rewrites the stem before an ending that takes 으, e.g. 춥 → 추우 and 듣 → 들으.
+///
+/// The 으 is part of the rewritten stem, so the ending attaches as after a vowel.
+pub(crate) fn alternate_eu(stem: &mut Vec<Jamo>, irregular: Irregular) {
+    let last = stem.len() - 1;
+    match irregular {
+        Irregular::Bieup => {
+            stem[last].2 = None;
+            stem.push((ChoseongCharacter::Ieung, JungseongCharacter::U, None));
+        }
+        Irregular::Digeut => {
+            stem[last].2 = Some(JongseongCharacter::Rieul);
+            stem.push((ChoseongCharacter::Ieung, JungseongCharacter::EU, None));
+        }
+        Irregular::Siot => {
+            stem[last].2 = None;
+            stem.push((ChoseongCharacter::Ieung, JungseongCharacter::EU, None));
+        }
+        Irregular::Hieuh => stem[last].2 = None,
+        Irregular::Reu | Irregular::Reo | Irregular::U | Irregular::Eu => {}
+    }
+}
+
+/// The vowel of 아/어 that follows the syllable before the last one, or 어 for a single syllable.
+fn harmony_before_last(stem: &[Jamo]) -> JungseongCharacter {
+    match stem.len().checked_sub(2).map(|index| &stem[index].1) {
+        Some(jungseong) if is_bright(jungseong) => JungseongCharacter::A,
+        _ => JungseongCharacter::EO,
+    }
+}
+
+/// Appends the -아/어 form of an irregular stem, e.g. 돕 → 도와, 부르 → 불러 and 푸 → 퍼.
+pub(crate) fn attach_a_irregular(stem: &mut Vec<Jamo>, irregular: Irregular) {
+    let text = to_text(stem);
+    let last = stem.len() - 1;
+    match irregular {
+        Irregular::Bieup => {
+            let u = if text.ends_with('돕') || text.ends_with('곱') {
+                JungseongCharacter::O
+            } else {
+                JungseongCharacter::U
+            };
+            stem[last].2 = None;
+            stem.push((ChoseongCharacter::Ieung, u, None));
+            attach_a(stem);
+        }
+        Irregular::Digeut => {
+            stem[last].2 = Some(JongseongCharacter::Rieul);
+            attach_a(stem);
+        }
+        Irregular::Siot => {
+            let a = if is_bright(&stem[last].1) {
+                JungseongCharacter::A
+            } else {
+                JungseongCharacter::EO
+            };
+            stem[last].2 = None;
+            stem.push((ChoseongCharacter::Ieung, a, None));
+        }
+        Irregular::Hieuh => {
+            let merged = if HADA_HIEUH.iter().any(|entry| text.ends_with(entry)) {
+                JungseongCharacter::AE
+            } else {
+                match stem[last].1 {
+                    JungseongCharacter::YA => JungseongCharacter::YAE,
+                    JungseongCharacter::EO => JungseongCharacter::E,
+                    JungseongCharacter::YEO => JungseongCharacter::YE,
+                    _ => JungseongCharacter::AE,
+                }
+            };
+            stem[last].1 = merged;
+            stem[last].2 = None;
+        }
+        Irregular::Reu => {
+            let a = harmony_before_last(stem);
+            stem[last - 1].2 = Some(JongseongCharacter::Rieul);
+            stem[last].1 = a;
+        }
+        Irregular::Reo => stem.push((ChoseongCharacter::Rieul, JungseongCharacter::EO, None)),
+        Irregular::U => stem[last].1 = JungseongCharacter::EO,
+        Irregular::Eu => stem[last].1 = harmony_before_last(stem),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classification() {
+        assert_eq!(classify_irregular("돕다"), Some(Irregular::Bieup));
+        assert_eq!(classify_irregular("자유롭다"), Some(Irregular::Bieup));
+        assert_eq!(classify_irregular("잡다"), None);
+        assert_eq!(classify_irregular("알아듣다"), Some(Irregular::Digeut));
+        assert_eq!(classify_irregular("받다"), None);
+        assert_eq!(classify_irregular("짓다"), Some(Irregular::Siot));
+        assert_eq!(classify_irregular("웃다"), None);
+        assert_eq!(classify_irregular("파랗다"), Some(Irregular::Hieuh));
+        assert_eq!(classify_irregular("좋다"), None);
+        assert_eq!(classify_irregular("부르다"), Some(Irregular::Reu));
+        assert_eq!(classify_irregular("따르다"), Some(Irregular::Eu));
+        assert_eq!(classify_irregular("푸르다"), Some(Irregular::Reo));
+        assert_eq!(classify_irregular("푸다"), Some(Irregular::U));
+        assert_eq!(classify_irregular("쓰다"), Some(Irregular::Eu));
+        assert_eq!(classify_irregular("먹다"), None);
+        assert_eq!(classify_irregular("먹"), None);
+    }
+
+    #[test]
+    fn bieup() {
+        assert_eq!(conjugate("돕다", Ending::AYo), Some("도와요".to_string()));
+        assert_eq!(conjugate("춥다", Ending::AtDa), Some("추웠다".to_string()));
+        assert_eq!(conjugate("춥다", Ending::N), Some("추운".to_string()));
+        assert_eq!(conjugate("돕다", Ending::Myeon), Some("도우면".to_string()));
+        assert_eq!(conjugate("돕다", Ending::Go), Some("돕고".to_string()));
+    }
+
+    #[test]
+    fn digeut() {
+        assert_eq!(conjugate("듣다", Ending::AYo), Some("들어요".to_string()));
+        assert_eq!(conjugate("듣다", Ending::Myeon), Some("들으면".to_string()));
+        assert_eq!(conjugate("걷다", Ending::L), Some("걸을".to_string()));
+        assert_eq!(conjugate("듣다", Ending::Neun), Some("듣는".to_string()));
+    }
+
+    #[test]
+    fn siot() {
+        assert_eq!(conjugate("짓다", Ending::AYo), Some("지어요".to_string()));
+        assert_eq!(conjugate("낫다", Ending::AtDa), Some("나았다".to_string()));
+        assert_eq!(conjugate("짓다", Ending::N), Some("지은".to_string()));
+    }
+
+    #[test]
+    fn hieuh() {
+        assert_eq!(conjugate("파랗다", Ending::AYo), Some("파래요".to_string()));
+        assert_eq!(
+            conjugate("하얗다", Ending::AtDa),
+            Some("하얬다".to_string())
+        );
+        assert_eq!(conjugate("그렇다", Ending::AYo), Some("그래요".to_string()));
+        assert_eq!(conjugate("파랗다", Ending::N), Some("파란".to_string()));
+        assert_eq!(
+            conjugate("파랗다", Ending::Nikka),
+            Some("파라니까".to_string())
+        );
+        assert_eq!(conjugate("파랗다", Ending::Go), Some("파랗고".to_string()));
+    }
+
+    #[test]
+    fn reu_and_reo() {
+        assert_eq!(conjugate("부르다", Ending::AYo), Some("불러요".to_string()));
+        assert_eq!(
+            conjugate("모르다", Ending::AtDa),
+            Some("몰랐다".to_string())
+        );
+        assert_eq!(conjugate("부르다", Ending::N), Some("부른".to_string()));
+        assert_eq!(
+            conjugate("푸르다", Ending::AYo),
+            Some("푸르러요".to_string())
+        );
+    }
+
+    #[test]
+    fn u_and_eu() {
+        assert_eq!(conjugate("푸다", Ending::AYo), Some("퍼요".to_string()));
+        assert_eq!(conjugate("쓰다", Ending::AYo), Some("써요".to_string()));
+        assert_eq!(
+            conjugate("아프다", Ending::AtDa),
+            Some("아팠다".to_string())
+        );
+        assert_eq!(conjugate("따르다", Ending::AYo), Some("따라요".to_string()));
+        assert_eq!(conjugate("쓰다", Ending::Myeon), Some("쓰면".to_string()));
+    }
+}
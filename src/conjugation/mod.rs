@@ -0,0 +1,381 @@
+use crate::characters::*;
+use crate::syllable::*;
+use std::convert::TryFrom;
+
+mod irregular;
+mod speech;
+mod stemmer;
+
+pub use irregular::*;
+pub use speech::*;
+pub use stemmer::*;
+
+pub(crate) type Jamo = (
+    ChoseongCharacter,
+    JungseongCharacter,
+    Option<JongseongCharacter>,
+);
+
+/// See:
+/// * https://en.wikipedia.org/wiki/Korean_verbs
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Ending {
+    /// -아/어요, e.g. 먹어요
+    AYo,
+    /// -았/었다, e.g. 먹었다
+    AtDa,
+    /// -(으)면, e.g. 먹으면
+    Myeon,
+    /// -(으)니까, e.g. 먹으니까
+    Nikka,
+    /// -고, e.g. 먹고
+    Go,
+    /// -는, e.g. 먹는. Adjectives take -(으)ㄴ instead and have no -는 form.
+    Neun,
+    /// -(으)ㄴ, e.g. 먹은
+    N,
+    /// -(으)ㄹ, e.g. 먹을
+    L,
+}
+
+pub(crate) fn to_jamo(text: &str) -> Option<Vec<Jamo>> {
+    text.chars()
+        .map(|character| Syllable::try_from(character).ok().map(Jamo::from))
+        .collect()
+}
+
+pub(crate) fn to_text(jamo: &[Jamo]) -> String {
+    jamo.iter()
+        .map(|syllable| char::from(Syllable::from(syllable.clone())))
+        .collect()
+}
+
+/// Adjective stems (형용사). Stems that are also read as verbs, such as 쓰다 (to write, or bitter)
+/// and 크다 (to grow, or big), are left out and conjugate as verbs.
+const ADJECTIVES: &[&str] = &[
+    "좋", "싫", "많", "작", "높", "낮", "길", "짧", "넓", "좁", "깊", "얕", "멀", "같", "젊",
+    "괜찮", "귀찮", "다르", "빠르", "느리", "예쁘", "기쁘", "슬프", "바쁘", "아프", "고프", "나쁘",
+    "비싸", "밝", "어둡", "춥", "덥", "쉽", "어렵", "맵", "곱", "밉", "가깝", "무겁", "가볍",
+    "귀엽", "고맙", "반갑", "즐겁", "뜨겁", "차갑", "두껍", "싱겁", "아깝", "무섭", "놀랍", "그립",
+    "가렵", "우습", "사납",
+];
+
+/// Roots of adjectives in -하다, e.g. 깨끗 of 깨끗하다.
+const HADA_ADJECTIVES: &[&str] = &[
+    "착", "편", "불편", "깨끗", "조용", "따뜻", "시원", "행복", "건강", "중요", "필요", "유명",
+    "친절", "복잡", "간단", "피곤", "똑똑", "심심", "위험", "안전", "익숙", "부족", "충분", "비슷",
+    "이상", "특별", "다양", "정확", "분명", "궁금", "미안", "적당", "솔직", "가난",
+];
+
+/// Derivational suffixes that make adjectives, e.g. 자유롭다. Their ㅂ always alternates, e.g. 자유로워.
+pub(crate) const ADJECTIVE_SUFFIXES: &[&str] = &["럽", "롭", "답"];
+
+/// Whether a stem is an adjective, which takes no -는.
+/// Stems in ㅎ that alternate, such as 파랗다, are all adjectives.
+///
+/// Adjectives are known only from `ADJECTIVES`, `HADA_ADJECTIVES` and `ADJECTIVE_SUFFIXES`,
+/// so any other predicate is treated as a verb.
+pub(crate) fn is_adjective(stem: &[Jamo]) -> bool {
+    let text = to_text(stem);
+    ADJECTIVES.iter().any(|adjective| text == *adjective)
+        || text
+            .strip_suffix('하')
+            .map_or(false, |root| HADA_ADJECTIVES.contains(&root))
+        || ADJECTIVE_SUFFIXES
+            .iter()
+            .any(|suffix| text.ends_with(suffix))
+        || classify_stem(stem) == Some(Irregular::Hieuh)
+}
+
+/// The stem of a dictionary form, e.g. 먹다 → 먹.
+pub(crate) fn stem(predicate: &str) -> Option<Vec<Jamo>> {
+    let stem = to_jamo(predicate.strip_suffix('다')?)?;
+    if stem.is_empty() {
+        return None;
+    }
+    Some(stem)
+}
+
+/// Whether the ending takes 아 rather than 어 after the vowel (모음 조화).
+pub(crate) fn is_bright(jungseong: &JungseongCharacter) -> bool {
+    matches!(
+        jungseong,
+        JungseongCharacter::A | JungseongCharacter::O | JungseongCharacter::YA
+    )
+}
+
+fn syllable(choseong: ChoseongCharacter, jungseong: JungseongCharacter) -> Jamo {
+    (choseong, jungseong, None)
+}
+
+/// Appends the -아/어 form, contracting the vowels where they merge,
+/// e.g. 가 + 아 → 가, 오 + 아 → 와, 마시 + 어 → 마셔 and 하 + 여 → 해.
+pub(crate) fn attach_a(stem: &mut Vec<Jamo>) {
+    let (choseong, jungseong, jongseong) = stem.last().cloned().expect("empty stem");
+    let a = if is_bright(&jungseong) {
+        JungseongCharacter::A
+    } else {
+        JungseongCharacter::EO
+    };
+
+    if jongseong.is_some() {
+        stem.push(syllable(ChoseongCharacter::Ieung, a));
+        return;
+    }
+
+    let contracted = match (&choseong, &jungseong) {
+        (ChoseongCharacter::Hieuh, JungseongCharacter::A) => Some(JungseongCharacter::AE),
+        (_, JungseongCharacter::A)
+        | (_, JungseongCharacter::EO)
+        | (_, JungseongCharacter::AE)
+        | (_, JungseongCharacter::E)
+        | (_, JungseongCharacter::YEO) => Some(jungseong.clone()),
+        (_, JungseongCharacter::O) => Some(JungseongCharacter::WA),
+        (_, JungseongCharacter::U) => Some(JungseongCharacter::WEO),
+        (_, JungseongCharacter::I) => Some(JungseongCharacter::YEO),
+        (_, JungseongCharacter::OE) => Some(JungseongCharacter::WAE),
+        _ => None,
+    };
+
+    match contracted {
+        Some(contracted) => {
+            let last = stem.len() - 1;
+            stem[last].1 = contracted;
+        }
+        None => stem.push(syllable(ChoseongCharacter::Ieung, a)),
+    }
+}
+
+/// Appends an ending that takes 으 after a consonant, e.g. 먹 + (으)면 → 먹으면.
+///
+/// The ending is given without 으. A final ㄹ takes no 으, and drops before ㄴ, e.g. 살 + (으)니까 → 사니까.
+fn attach_eu(stem: &mut Vec<Jamo>, ending: &[Jamo]) {
+    let last = stem.len() - 1;
+    match &stem[last].2 {
+        Some(JongseongCharacter::Rieul) if ending[0].0 == ChoseongCharacter::Nieun => {
+            stem[last].2 = None
+        }
+        Some(JongseongCharacter::Rieul) | None => {}
+        Some(_) => stem.push(syllable(ChoseongCharacter::Ieung, JungseongCharacter::EU)),
+    }
+    stem.extend_from_slice(ending);
+}
+
+/// Appends an ending made of a single final, taking 으 after a consonant, e.g. 가 + (으)ㄴ → 간.
+///
+/// A final ㄹ drops before ㄴ and merges with ㄹ, e.g. 살 + (으)ㄴ → 산 and 살 + (으)ㄹ → 살.
+fn attach_eu_jongseong(stem: &mut Vec<Jamo>, jongseong: JongseongCharacter) {
+    let last = stem.len() - 1;
+    match &stem[last].2 {
+        Some(JongseongCharacter::Rieul) => stem[last].2 = Some(jongseong),
+        Some(_) => stem.push((
+            ChoseongCharacter::Ieung,
+            JungseongCharacter::EU,
+            Some(jongseong),
+        )),
+        None => stem[last].2 = Some(jongseong),
+    }
+}
+
+/// Appends the -아/어 form, following the alternation of an irregular stem.
+pub(crate) fn attach_a_with(stem: &mut Vec<Jamo>, irregular: Option<Irregular>) {
+    match irregular {
+        Some(irregular) => attach_a_irregular(stem, irregular),
+        None => attach_a(stem),
+    }
+}
+
+/// Conjugates a stem given as syllables.
+pub(crate) fn conjugate_stem(
+    mut stem: Vec<Jamo>,
+    irregular: Option<Irregular>,
+    ending: Ending,
+) -> String {
+    if let (Some(irregular), Ending::Myeon | Ending::Nikka | Ending::N | Ending::L) =
+        (irregular, ending)
+    {
+        alternate_eu(&mut stem, irregular);
+    }
+
+    let last = stem.len() - 1;
+    match ending {
+        Ending::AYo => {
+            attach_a_with(&mut stem, irregular);
+            stem.push(syllable(ChoseongCharacter::Ieung, JungseongCharacter::YO));
+        }
+        Ending::AtDa => {
+            attach_a_with(&mut stem, irregular);
+            let last = stem.len() - 1;
+            stem[last].2 = Some(JongseongCharacter::SsangSiot);
+            stem.push(syllable(ChoseongCharacter::Digeut, JungseongCharacter::A));
+        }
+        Ending::Myeon => attach_eu(
+            &mut stem,
+            &[(
+                ChoseongCharacter::Mieum,
+                JungseongCharacter::YEO,
+                Some(JongseongCharacter::Nieun),
+            )],
+        ),
+        Ending::Nikka => attach_eu(
+            &mut stem,
+            &[
+                syllable(ChoseongCharacter::Nieun, JungseongCharacter::I),
+                syllable(ChoseongCharacter::SsangGiyeok, JungseongCharacter::A),
+            ],
+        ),
+        Ending::Go => stem.push(syllable(ChoseongCharacter::Giyeok, JungseongCharacter::O)),
+        Ending::Neun => {
+            if stem[last].2 == Some(JongseongCharacter::Rieul) {
+                stem[last].2 = None;
+            }
+            stem.push((
+                ChoseongCharacter::Nieun,
+                JungseongCharacter::EU,
+                Some(JongseongCharacter::Nieun),
+            ));
+        }
+        Ending::N => attach_eu_jongseong(&mut stem, JongseongCharacter::Nieun),
+        Ending::L => attach_eu_jongseong(&mut stem, JongseongCharacter::Rieul),
+    }
+
+    to_text(&stem)
+}
+
+/// Conjugates a dictionary form such as 먹다 with an ending.
+/// Irregular predicates are recognized by `classify_irregular`.
+///
+/// Returns `None` when the predicate is not hangul ending with 다,
+/// or for `Ending::Neun` on an adjective, e.g. 좋다.
+pub fn conjugate(predicate: &str, ending: Ending) -> Option<String> {
+    let stem = stem(predicate)?;
+    if ending == Ending::Neun && is_adjective(&stem) {
+        return None;
+    }
+    let irregular = classify_stem(&stem);
+    Some(conjugate_stem(stem, irregular, ending))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn forms(predicate: &str) -> Vec<String> {
+        [
+            Ending::AYo,
+            Ending::AtDa,
+            Ending::Myeon,
+            Ending::Nikka,
+            Ending::Go,
+            Ending::Neun,
+            Ending::N,
+            Ending::L,
+        ]
+        .iter()
+        .map(|ending| conjugate(predicate, *ending).unwrap())
+        .collect()
+    }
+
+    #[test]
+    fn consonant_stems() {
+        assert_eq!(
+            forms("먹다"),
+            vec![
+                "먹어요",
+                "먹었다",
+                "먹으면",
+                "먹으니까",
+                "먹고",
+                "먹는",
+                "먹은",
+                "먹을"
+            ]
+        );
+        assert_eq!(
+            forms("읽다"),
+            vec![
+                "읽어요",
+                "읽었다",
+                "읽으면",
+                "읽으니까",
+                "읽고",
+                "읽는",
+                "읽은",
+                "읽을"
+            ]
+        );
+    }
+
+    #[test]
+    fn adjectives() {
+        assert_eq!(conjugate("좋다", Ending::Neun), None);
+        assert_eq!(conjugate("좋다", Ending::N), Some("좋은".to_string()));
+        assert_eq!(conjugate("좋다", Ending::AYo), Some("좋아요".to_string()));
+        assert_eq!(conjugate("파랗다", Ending::Neun), None);
+        assert_eq!(conjugate("자유롭다", Ending::Neun), None);
+        assert_eq!(conjugate("있다", Ending::Neun), Some("있는".to_string()));
+        assert_eq!(conjugate("쓰다", Ending::Neun), Some("쓰는".to_string()));
+        assert_eq!(conjugate("깨끗하다", Ending::Neun), None);
+        assert_eq!(conjugate("가깝다", Ending::Neun), None);
+        assert_eq!(
+            conjugate("공부하다", Ending::Neun),
+            Some("공부하는".to_string())
+        );
+    }
+
+    #[test]
+    fn vowel_stems() {
+        assert_eq!(
+            forms("가다"),
+            vec!["가요", "갔다", "가면", "가니까", "가고", "가는", "간", "갈"]
+        );
+        assert_eq!(
+            forms("하다"),
+            vec!["해요", "했다", "하면", "하니까", "하고", "하는", "한", "할"]
+        );
+    }
+
+    #[test]
+    fn contraction() {
+        assert_eq!(conjugate("오다", Ending::AYo), Some("와요".to_string()));
+        assert_eq!(conjugate("보다", Ending::AtDa), Some("봤다".to_string()));
+        assert_eq!(conjugate("주다", Ending::AYo), Some("줘요".to_string()));
+        assert_eq!(conjugate("배우다", Ending::AYo), Some("배워요".to_string()));
+        assert_eq!(
+            conjugate("마시다", Ending::AtDa),
+            Some("마셨다".to_string())
+        );
+        assert_eq!(conjugate("되다", Ending::AYo), Some("돼요".to_string()));
+        assert_eq!(conjugate("서다", Ending::AYo), Some("서요".to_string()));
+        assert_eq!(conjugate("보내다", Ending::AYo), Some("보내요".to_string()));
+        assert_eq!(conjugate("쉬다", Ending::AYo), Some("쉬어요".to_string()));
+        assert_eq!(
+            conjugate("공부하다", Ending::AtDa),
+            Some("공부했다".to_string())
+        );
+    }
+
+    #[test]
+    fn rieul_stems() {
+        assert_eq!(
+            forms("살다"),
+            vec![
+                "살아요",
+                "살았다",
+                "살면",
+                "사니까",
+                "살고",
+                "사는",
+                "산",
+                "살"
+            ]
+        );
+    }
+
+    #[test]
+    fn not_predicates() {
+        assert_eq!(conjugate("먹", Ending::Go), None);
+        assert_eq!(conjugate("다", Ending::Go), None);
+        assert_eq!(conjugate("eat다", Ending::Go), None);
+    }
+}
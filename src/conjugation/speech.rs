@@ -0,0 +1,306 @@
+use super::*;
+
+/// The speech levels (상대 높임법) of a sentence-final predicate.
+///
+/// See:
+/// * https://en.wikipedia.org/wiki/Korean_speech_levels
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SpeechLevel {
+    /// 하십시오체, formal and polite, e.g. 먹습니다.
+    Hasipsio,
+    /// 해요체, informal and polite, e.g. 먹어요.
+    Haeyo,
+    /// 해체, informal and casual, e.g. 먹어.
+    Hae,
+    /// 해라체, formal and casual, e.g. 먹는다.
+    Haera,
+}
+
+/// Whether a stem takes -다 in 해라체, as adjectives and 있다, 없다 and their compounds such as 맛있다 do.
+fn takes_da(stem: &[Jamo]) -> bool {
+    let text = to_text(stem);
+    is_adjective(stem) || text.ends_with('있') || text.ends_with('없')
+}
+
+/// Appends the subject honorific -(으)시-, e.g. 가 → 가시, 읽 → 읽으시 and 살 → 사시.
+fn attach_honorific(stem: &mut Vec<Jamo>) {
+    match classify_stem(stem) {
+        Some(irregular) => alternate_eu(stem, irregular),
+        None => {
+            let last = stem.len() - 1;
+            match stem[last].2 {
+                Some(JongseongCharacter::Rieul) => stem[last].2 = None,
+                Some(_) => stem.push((ChoseongCharacter::Ieung, JungseongCharacter::EU, None)),
+                None => {}
+            }
+        }
+    }
+    stem.push((ChoseongCharacter::Siot, JungseongCharacter::I, None));
+}
+
+/// Appends the past -았/었-, e.g. 가 → 갔 and 먹 → 먹었.
+fn attach_past(stem: &mut Vec<Jamo>) {
+    let irregular = classify_stem(stem);
+    attach_a_with(stem, irregular);
+    let last = stem.len() - 1;
+    stem[last].2 = Some(JongseongCharacter::SsangSiot);
+}
+
+/// Appends an ending that starts with a final on a vowel, or with its own syllable after a consonant,
+/// e.g. 가 + ㅂ니다 → 갑니다 and 먹 + 습니다 → 먹습니다. A final ㄹ drops first, e.g. 살 → 삽니다.
+fn attach_vowel_or_consonant(
+    stem: &mut Vec<Jamo>,
+    jongseong: JongseongCharacter,
+    after_consonant: &[Jamo],
+    rest: &[Jamo],
+) {
+    let last = stem.len() - 1;
+    if stem[last].2 == Some(JongseongCharacter::Rieul) {
+        stem[last].2 = None;
+    }
+    match stem[last].2 {
+        Some(_) => stem.extend_from_slice(after_consonant),
+        None => stem[last].2 = Some(jongseong),
+    }
+    stem.extend_from_slice(rest);
+}
+
+/// Renders a stem in a speech level, e.g. 먹, past, 하십시오체 → 먹었습니다.
+fn render(mut stem: Vec<Jamo>, level: SpeechLevel, honorific: bool, past: bool) -> String {
+    let adjective = takes_da(&stem);
+    if honorific {
+        attach_honorific(&mut stem);
+    }
+    if past {
+        attach_past(&mut stem);
+    }
+
+    let da = (ChoseongCharacter::Digeut, JungseongCharacter::A, None);
+    let yo = (ChoseongCharacter::Ieung, JungseongCharacter::YO, None);
+    let eo = (ChoseongCharacter::Ieung, JungseongCharacter::EO, None);
+    let present_honorific = honorific && !past;
+    match level {
+        SpeechLevel::Hasipsio => attach_vowel_or_consonant(
+            &mut stem,
+            JongseongCharacter::Bieup,
+            &[(
+                ChoseongCharacter::Siot,
+                JungseongCharacter::EU,
+                Some(JongseongCharacter::Bieup),
+            )],
+            &[(ChoseongCharacter::Nieun, JungseongCharacter::I, None), da],
+        ),
+        // -(으)시- and -어요 make -(으)세요 rather than -(으)셔요.
+        SpeechLevel::Haeyo if present_honorific => {
+            let last = stem.len() - 1;
+            stem[last].1 = JungseongCharacter::E;
+            stem.push(yo);
+        }
+        SpeechLevel::Haeyo | SpeechLevel::Hae => {
+            if past {
+                stem.push(eo);
+            } else {
+                let irregular = classify_stem(&stem);
+                attach_a_with(&mut stem, irregular);
+            }
+            if level == SpeechLevel::Haeyo {
+                stem.push(yo);
+            }
+        }
+        SpeechLevel::Haera if past || adjective => stem.push(da),
+        SpeechLevel::Haera => attach_vowel_or_consonant(
+            &mut stem,
+            JongseongCharacter::Nieun,
+            &[(
+                ChoseongCharacter::Nieun,
+                JungseongCharacter::EU,
+                Some(JongseongCharacter::Nieun),
+            )],
+            &[da],
+        ),
+    }
+
+    to_text(&stem)
+}
+
+const LEVELS: [SpeechLevel; 4] = [
+    SpeechLevel::Hasipsio,
+    SpeechLevel::Haeyo,
+    SpeechLevel::Hae,
+    SpeechLevel::Haera,
+];
+
+/// The stem of a dictionary form the predicate was read as, and whether the predicate is past.
+fn read(surface: &[Jamo], form: &str) -> Option<(Vec<Jamo>, bool)> {
+    let stem = stem(form)?;
+
+    // The past -았/었- leaves ㅆ on a syllable after the stem, e.g. 먹었 or 갔.
+    let past = surface
+        .iter()
+        .enumerate()
+        .skip(stem.len() - 1)
+        .any(|(index, syllable)| {
+            syllable.2 == Some(JongseongCharacter::SsangSiot)
+                && (index >= stem.len() || !form.ends_with("있다"))
+        });
+    Some((stem, past))
+}
+
+/// Re-renders a sentence-final predicate in a speech level, keeping its tense,
+/// e.g. 먹었어요 → 먹었습니다 in 하십시오체, or 가 → 가세요 in 해요체 with the subject honorific.
+///
+/// The predicate may be a dictionary form or any form `deconjugate` reads. The first reading that
+/// renders back into the predicate is used, e.g. 마시다 rather than 마다 for 마셔요,
+/// or else the most likely one. Any -(으)시- in it is dropped unless `honorific` is set.
+/// Returns `None` when the predicate cannot be read.
+pub fn change_speech_level(predicate: &str, level: SpeechLevel, honorific: bool) -> Option<String> {
+    let surface = to_jamo(predicate)?;
+    let readings: Vec<(Vec<Jamo>, bool)> = deconjugate(predicate)
+        .iter()
+        .filter_map(|form| read(&surface, form))
+        .collect();
+
+    let renders_back = |(stem, past): &&(Vec<Jamo>, bool)| {
+        LEVELS.iter().any(|level| {
+            [false, true]
+                .iter()
+                .any(|honorific| render(stem.clone(), *level, *honorific, *past) == predicate)
+        })
+    };
+    let (stem, past) = readings
+        .iter()
+        .find(renders_back)
+        .or_else(|| readings.first())?
+        .clone();
+
+    Some(render(stem, level, honorific, past))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn levels(predicate: &str, honorific: bool) -> Vec<String> {
+        LEVELS
+            .iter()
+            .map(|level| change_speech_level(predicate, *level, honorific).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn present() {
+        assert_eq!(
+            levels("먹다", false),
+            vec!["먹습니다", "먹어요", "먹어", "먹는다"]
+        );
+        assert_eq!(levels("가다", false), vec!["갑니다", "가요", "가", "간다"]);
+        assert_eq!(
+            levels("살다", false),
+            vec!["삽니다", "살아요", "살아", "산다"]
+        );
+        assert_eq!(
+            levels("좋다", false),
+            vec!["좋습니다", "좋아요", "좋아", "좋다"]
+        );
+        assert_eq!(
+            levels("돕다", false),
+            vec!["돕습니다", "도와요", "도와", "돕는다"]
+        );
+        assert_eq!(levels("자유롭다", false)[3], "자유롭다");
+        assert_eq!(levels("깨끗하다", false)[3], "깨끗하다");
+        assert_eq!(levels("가까워요", false)[3], "가깝다");
+    }
+
+    #[test]
+    fn common_verbs() {
+        assert_eq!(levels("서요", false), vec!["섭니다", "서요", "서", "선다"]);
+        assert_eq!(
+            levels("만나요", false),
+            vec!["만납니다", "만나요", "만나", "만난다"]
+        );
+        assert_eq!(
+            levels("만들어요", false),
+            vec!["만듭니다", "만들어요", "만들어", "만든다"]
+        );
+        assert_eq!(
+            levels("건너요", false),
+            vec!["건넙니다", "건너요", "건너", "건넌다"]
+        );
+        assert_eq!(
+            levels("마셔요", false),
+            vec!["마십니다", "마셔요", "마셔", "마신다"]
+        );
+        assert_eq!(
+            levels("배워요", false),
+            vec!["배웁니다", "배워요", "배워", "배운다"]
+        );
+        assert_eq!(
+            levels("되었어요", false),
+            vec!["됐습니다", "됐어요", "됐어", "됐다"]
+        );
+    }
+
+    #[test]
+    fn past() {
+        assert_eq!(
+            levels("먹었어요", false),
+            vec!["먹었습니다", "먹었어요", "먹었어", "먹었다"]
+        );
+        assert_eq!(
+            levels("갔다", false),
+            vec!["갔습니다", "갔어요", "갔어", "갔다"]
+        );
+        assert_eq!(
+            levels("있었어", false),
+            vec!["있었습니다", "있었어요", "있었어", "있었다"]
+        );
+    }
+
+    #[test]
+    fn homographs() {
+        // 쓰다 is read as "to write" rather than "bitter".
+        assert_eq!(
+            change_speech_level("쓰다", SpeechLevel::Haera, false),
+            Some("쓴다".to_string())
+        );
+        assert_eq!(
+            change_speech_level("크다", SpeechLevel::Haera, false),
+            Some("큰다".to_string())
+        );
+    }
+
+    #[test]
+    fn honorific() {
+        assert_eq!(
+            levels("가다", true),
+            vec!["가십니다", "가세요", "가셔", "가신다"]
+        );
+        assert_eq!(
+            levels("읽어", true),
+            vec!["읽으십니다", "읽으세요", "읽으셔", "읽으신다"]
+        );
+        assert_eq!(
+            levels("먹었다", true),
+            vec!["먹으셨습니다", "먹으셨어요", "먹으셨어", "먹으셨다"]
+        );
+        assert_eq!(levels("돕다", true)[1], "도우세요");
+        assert_eq!(levels("좋다", true)[3], "좋으시다");
+    }
+
+    #[test]
+    fn dropping_the_honorific() {
+        assert_eq!(
+            change_speech_level("가셨습니다", SpeechLevel::Hae, false),
+            Some("갔어".to_string())
+        );
+        assert_eq!(
+            change_speech_level("읽으세요", SpeechLevel::Haera, false),
+            Some("읽는다".to_string())
+        );
+    }
+
+    #[test]
+    fn unreadable() {
+        assert_eq!(change_speech_level("run", SpeechLevel::Haeyo, false), None);
+    }
+}
@@ -0,0 +1,507 @@
+use super::*;
+use std::cmp::Reverse;
+
+/// What an ending attaches to, which decides how the stem in front of it is recovered.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Form {
+    /// The bare stem, e.g. 먹 in 먹고. A final ㄹ may have dropped, e.g. 사 in 사는.
+    Stem { drops_rieul: bool },
+    /// The -아/어 form, e.g. 먹어 in 먹어요.
+    Infinitive,
+    /// The stem with 으 after a consonant, e.g. 먹으 in 먹으면.
+    Eu { drops_rieul: bool },
+}
+
+/// Endings peeled off the end of a word, tried in order until one matches.
+/// Some also take a final on the syllable before them, e.g. the ㅂ of 갑니다.
+/// A word that ends in none of them is read as an adnominal or as the bare -아/어 form.
+const ENDINGS: &[(&str, Option<JongseongCharacter>, Form)] = &[
+    ("습니다", None, Form::Stem { drops_rieul: false }),
+    ("습니까", None, Form::Stem { drops_rieul: false }),
+    (
+        "니다",
+        Some(JongseongCharacter::Bieup),
+        Form::Stem { drops_rieul: true },
+    ),
+    (
+        "니까",
+        Some(JongseongCharacter::Bieup),
+        Form::Stem { drops_rieul: true },
+    ),
+    ("니까", None, Form::Eu { drops_rieul: true }),
+    ("세요", None, Form::Eu { drops_rieul: true }),
+    ("지요", None, Form::Stem { drops_rieul: false }),
+    ("지만", None, Form::Stem { drops_rieul: false }),
+    ("는다", None, Form::Stem { drops_rieul: false }),
+    (
+        "다",
+        Some(JongseongCharacter::Nieun),
+        Form::Stem { drops_rieul: true },
+    ),
+    ("다", None, Form::Stem { drops_rieul: false }),
+    ("요", None, Form::Infinitive),
+    ("고", None, Form::Stem { drops_rieul: false }),
+    ("죠", None, Form::Stem { drops_rieul: false }),
+    ("게", None, Form::Stem { drops_rieul: false }),
+    ("는", None, Form::Stem { drops_rieul: true }),
+    ("면", None, Form::Eu { drops_rieul: false }),
+    ("서", None, Form::Infinitive),
+    ("도", None, Form::Infinitive),
+];
+
+fn with_jongseong(mut stem: Vec<Jamo>, jongseong: Option<JongseongCharacter>) -> Vec<Jamo> {
+    let last = stem.len() - 1;
+    stem[last].2 = jongseong;
+    stem
+}
+
+fn is_syllable(
+    syllable: &Jamo,
+    choseong: ChoseongCharacter,
+    jungseong: JungseongCharacter,
+) -> bool {
+    *syllable == (choseong, jungseong, None)
+}
+
+/// The stem in front of an ending that takes 으, e.g. 먹 → 먹으, 춥 → 추우 and 살 → 사 before ㄴ.
+fn eu_form(mut stem: Vec<Jamo>, drops_rieul: bool) -> Vec<Jamo> {
+    if let Some(irregular) = classify_stem(&stem) {
+        alternate_eu(&mut stem, irregular);
+        return stem;
+    }
+
+    let last = stem.len() - 1;
+    match stem[last].2 {
+        Some(JongseongCharacter::Rieul) if drops_rieul => stem[last].2 = None,
+        Some(JongseongCharacter::Rieul) | None => {}
+        Some(_) => stem.push((ChoseongCharacter::Ieung, JungseongCharacter::EU, None)),
+    }
+    stem
+}
+
+/// The -아/어 form of a stem, e.g. 돕 → 도와.
+fn infinitive(mut stem: Vec<Jamo>) -> Vec<Jamo> {
+    let irregular = classify_stem(&stem);
+    attach_a_with(&mut stem, irregular);
+    stem
+}
+
+/// The -아/어 form of a regular stem with the vowels left apart, e.g. 보 → 보아, 되 → 되어 and 하 → 하여.
+/// Only vowels that may contract have one, so 가 has none.
+fn uncontracted_infinitive(mut stem: Vec<Jamo>) -> Option<Vec<Jamo>> {
+    if classify_stem(&stem).is_some() {
+        return None;
+    }
+    let a = match stem.last()? {
+        (ChoseongCharacter::Hieuh, JungseongCharacter::A, None) => JungseongCharacter::YEO,
+        (_, JungseongCharacter::O, None) => JungseongCharacter::A,
+        (_, JungseongCharacter::U, None)
+        | (_, JungseongCharacter::OE, None)
+        | (_, JungseongCharacter::I, None)
+        | (_, JungseongCharacter::AE, None)
+        | (_, JungseongCharacter::E, None) => JungseongCharacter::EO,
+        _ => return None,
+    };
+    stem.push((ChoseongCharacter::Ieung, a, None));
+    Some(stem)
+}
+
+/// Stems that could have produced the surface before an -아/어 ending, unverified.
+fn infinitive_guesses(surface: &[Jamo]) -> Vec<Vec<Jamo>> {
+    let mut guesses = vec![];
+    let (choseong, jungseong, jongseong) = surface.last().cloned().expect("empty surface");
+    if jongseong.is_some() {
+        return guesses;
+    }
+
+    let last = surface.len() - 1;
+    let prefix = &surface[..last];
+    if !prefix.is_empty() && choseong == ChoseongCharacter::Ieung {
+        let prefix_jongseong = &prefix[last - 1].2;
+        match jungseong {
+            JungseongCharacter::A | JungseongCharacter::EO => {
+                guesses.push(prefix.to_vec());
+                match prefix_jongseong {
+                    None => guesses.push(with_jongseong(
+                        prefix.to_vec(),
+                        Some(JongseongCharacter::Siot),
+                    )),
+                    Some(JongseongCharacter::Rieul) => guesses.push(with_jongseong(
+                        prefix.to_vec(),
+                        Some(JongseongCharacter::Digeut),
+                    )),
+                    Some(_) => {}
+                }
+            }
+            // 하 + 여 left apart, e.g. 하여.
+            JungseongCharacter::YEO
+                if is_syllable(
+                    &prefix[last - 1],
+                    ChoseongCharacter::Hieuh,
+                    JungseongCharacter::A,
+                ) =>
+            {
+                guesses.push(prefix.to_vec());
+            }
+            JungseongCharacter::WA | JungseongCharacter::WEO if prefix_jongseong.is_none() => {
+                guesses.push(with_jongseong(
+                    prefix.to_vec(),
+                    Some(JongseongCharacter::Bieup),
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    if !prefix.is_empty()
+        && choseong == ChoseongCharacter::Rieul
+        && (jungseong == JungseongCharacter::A || jungseong == JungseongCharacter::EO)
+    {
+        if prefix[last - 1].2 == Some(JongseongCharacter::Rieul) {
+            let mut guess = with_jongseong(prefix.to_vec(), None);
+            guess.push((ChoseongCharacter::Rieul, JungseongCharacter::EU, None));
+            guesses.push(guess);
+        }
+        if is_syllable(
+            &prefix[last - 1],
+            ChoseongCharacter::Rieul,
+            JungseongCharacter::EU,
+        ) {
+            guesses.push(prefix.to_vec());
+        }
+    }
+
+    let vowels: &[(JungseongCharacter, Option<JongseongCharacter>)] = match jungseong {
+        JungseongCharacter::A => &[
+            (JungseongCharacter::A, None),
+            (JungseongCharacter::EU, None),
+        ],
+        JungseongCharacter::EO => &[
+            (JungseongCharacter::EO, None),
+            (JungseongCharacter::EU, None),
+            (JungseongCharacter::U, None),
+        ],
+        JungseongCharacter::AE => &[
+            (JungseongCharacter::AE, None),
+            (JungseongCharacter::A, None),
+            (JungseongCharacter::A, Some(JongseongCharacter::Hieuh)),
+            (JungseongCharacter::EO, Some(JongseongCharacter::Hieuh)),
+        ],
+        JungseongCharacter::E => &[
+            (JungseongCharacter::E, None),
+            (JungseongCharacter::EO, Some(JongseongCharacter::Hieuh)),
+        ],
+        JungseongCharacter::YEO if last == 0 => &[
+            (JungseongCharacter::YEO, None),
+            (JungseongCharacter::I, None),
+        ],
+        JungseongCharacter::YEO => &[(JungseongCharacter::I, None)],
+        JungseongCharacter::YAE => &[(JungseongCharacter::YA, Some(JongseongCharacter::Hieuh))],
+        JungseongCharacter::YE => &[(JungseongCharacter::YEO, Some(JongseongCharacter::Hieuh))],
+        JungseongCharacter::WA => &[(JungseongCharacter::O, None)],
+        JungseongCharacter::WEO => &[(JungseongCharacter::U, None)],
+        JungseongCharacter::WAE => &[(JungseongCharacter::OE, None)],
+        _ => &[],
+    };
+    for (vowel, jongseong) in vowels {
+        // 해 is always 하 + 여.
+        if choseong == ChoseongCharacter::Hieuh
+            && jungseong == JungseongCharacter::AE
+            && (*vowel != JungseongCharacter::A || jongseong.is_some())
+        {
+            continue;
+        }
+        // The ㅎ irregulars are adjectives of two syllables or more, e.g. 파랗다.
+        if jongseong.is_some() && last == 0 {
+            continue;
+        }
+        let mut guess = surface.to_vec();
+        guess[last].1 = vowel.clone();
+        guess[last].2 = jongseong.clone();
+        guesses.push(guess);
+    }
+
+    guesses
+}
+
+/// Stems that could have produced the surface before an ending that takes 으, unverified.
+fn eu_guesses(surface: &[Jamo], drops_rieul: bool) -> Vec<Vec<Jamo>> {
+    let mut guesses = vec![surface.to_vec()];
+    let last = surface.len() - 1;
+    if surface[last].2.is_some() {
+        return guesses;
+    }
+
+    if drops_rieul {
+        guesses.push(with_jongseong(
+            surface.to_vec(),
+            Some(JongseongCharacter::Rieul),
+        ));
+    }
+    if last > 0 {
+        guesses.push(with_jongseong(
+            surface.to_vec(),
+            Some(JongseongCharacter::Hieuh),
+        ));
+    }
+
+    if last > 0 {
+        let prefix = &surface[..last];
+        if is_syllable(
+            &surface[last],
+            ChoseongCharacter::Ieung,
+            JungseongCharacter::EU,
+        ) {
+            guesses.push(prefix.to_vec());
+            match prefix[last - 1].2 {
+                Some(JongseongCharacter::Rieul) => guesses.push(with_jongseong(
+                    prefix.to_vec(),
+                    Some(JongseongCharacter::Digeut),
+                )),
+                None => guesses.push(with_jongseong(
+                    prefix.to_vec(),
+                    Some(JongseongCharacter::Siot),
+                )),
+                Some(_) => {}
+            }
+        }
+        if is_syllable(
+            &surface[last],
+            ChoseongCharacter::Ieung,
+            JungseongCharacter::U,
+        ) && prefix[last - 1].2.is_none()
+        {
+            guesses.push(with_jongseong(
+                prefix.to_vec(),
+                Some(JongseongCharacter::Bieup),
+            ));
+        }
+    }
+
+    guesses
+}
+
+/// Stems never end in a bare 아 or 어, or in 으 after another syllable,
+/// which are always endings, e.g. 먹어, 먹으 and 먹을.
+/// Nor are they the bare 으 or the honorific 시 and 셔 alone, e.g. in 었어 and 셨어요.
+fn is_plausible(stem: &[Jamo]) -> bool {
+    match stem {
+        [(ChoseongCharacter::Ieung, JungseongCharacter::EU, None)]
+        | [(ChoseongCharacter::Siot, JungseongCharacter::I, None)]
+        | [(ChoseongCharacter::Siot, JungseongCharacter::YEO, None)] => false,
+        [.., (ChoseongCharacter::Ieung, JungseongCharacter::A, None)]
+        | [.., (ChoseongCharacter::Ieung, JungseongCharacter::EO, None)] => false,
+        [_, .., (ChoseongCharacter::Ieung, JungseongCharacter::EU, _)] => false,
+        [_, ..] => true,
+        [] => false,
+    }
+}
+
+/// Stems that produce the surface in the given form, following the past -았/었-,
+/// the honorific -(으)시- and the auxiliary 주다 further back.
+fn candidates(surface: &[Jamo], form: Form) -> Vec<Vec<Jamo>> {
+    if surface.is_empty() {
+        return vec![];
+    }
+
+    let verified: Vec<Vec<Jamo>> = match form {
+        Form::Stem { drops_rieul } => {
+            let mut stems = vec![surface.to_vec()];
+            if drops_rieul && surface[surface.len() - 1].2.is_none() {
+                stems.push(with_jongseong(
+                    surface.to_vec(),
+                    Some(JongseongCharacter::Rieul),
+                ));
+            }
+            stems
+        }
+        Form::Infinitive => infinitive_guesses(surface)
+            .into_iter()
+            .filter(|guess| {
+                infinitive(guess.clone()) == surface
+                    || uncontracted_infinitive(guess.clone()).map_or(false, |form| form == surface)
+            })
+            .collect(),
+        Form::Eu { drops_rieul } => eu_guesses(surface, drops_rieul)
+            .into_iter()
+            .filter(|guess| eu_form(guess.clone(), drops_rieul) == surface)
+            .collect(),
+    };
+
+    let mut stems = vec![];
+    for stem in verified.into_iter().filter(|stem| is_plausible(stem)) {
+        let last = stem.len() - 1;
+        let prefix = &stem[..last];
+        if stem[last].2 == Some(JongseongCharacter::SsangSiot) {
+            stems.extend(candidates(
+                &with_jongseong(stem.clone(), None),
+                Form::Infinitive,
+            ));
+            if !to_text(&stem).ends_with('있') {
+                continue;
+            }
+        }
+        if !prefix.is_empty()
+            && is_syllable(&stem[last], ChoseongCharacter::Siot, JungseongCharacter::I)
+        {
+            stems.extend(candidates(prefix, Form::Eu { drops_rieul: true }));
+        }
+        if !prefix.is_empty()
+            && is_syllable(&stem[last], ChoseongCharacter::Jieut, JungseongCharacter::U)
+        {
+            stems.extend(candidates(prefix, Form::Infinitive));
+        }
+        stems.push(stem);
+    }
+    stems
+}
+
+/// Candidate stems of a word, from the first ending that matches.
+fn peel(surface: &[Jamo]) -> Vec<Vec<Jamo>> {
+    let text = to_text(surface);
+    for (ending, jongseong, form) in ENDINGS {
+        let rest = match text.strip_suffix(ending) {
+            Some(rest) if !rest.is_empty() => &surface[..rest.chars().count()],
+            _ => continue,
+        };
+        match jongseong {
+            Some(jongseong) if rest[rest.len() - 1].2.as_ref() == Some(jongseong) => {
+                return candidates(&with_jongseong(rest.to_vec(), None), *form);
+            }
+            Some(_) => {}
+            None => return candidates(rest, *form),
+        }
+    }
+
+    // The adnominal -(으)ㄴ and -(으)ㄹ, e.g. 먹은 and 갈.
+    let last = surface.len() - 1;
+    match surface[last].2 {
+        Some(JongseongCharacter::Nieun) | Some(JongseongCharacter::Rieul) => candidates(
+            &with_jongseong(surface.to_vec(), None),
+            Form::Eu { drops_rieul: true },
+        ),
+        _ => candidates(surface, Form::Infinitive),
+    }
+}
+
+/// Peels the endings off an inflected word and returns the dictionary forms it may come from,
+/// e.g. 먹었습니다 → 먹다 and 도와줘 → 돕다.
+///
+/// Candidates are not checked against a dictionary, so a word usually has several.
+/// Stems spelled out unchanged in the word come first, the longest first,
+/// e.g. 만나다 before 만느다 for 만나요 and 마시다 before 마다 for 마시었다.
+pub fn deconjugate(word: &str) -> Vec<String> {
+    let surface = match to_jamo(word) {
+        Some(surface) if !surface.is_empty() => surface,
+        _ => return vec![],
+    };
+    let mut stems = peel(&surface);
+    stems.sort_by_key(|stem| {
+        Reverse(if surface.starts_with(stem) {
+            stem.len()
+        } else {
+            0
+        })
+    });
+    let mut forms: Vec<String> = vec![];
+    for stem in stems {
+        let form = format!("{}다", to_text(&stem));
+        if !forms.contains(&form) {
+            forms.push(form);
+        }
+    }
+    forms
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn has(word: &str, predicate: &str) -> bool {
+        deconjugate(word).iter().any(|form| form == predicate)
+    }
+
+    #[test]
+    fn regular() {
+        assert!(has("먹다", "먹다"));
+        assert!(has("먹었습니다", "먹다"));
+        assert!(has("먹었다", "먹다"));
+        assert!(has("먹어요", "먹다"));
+        assert!(has("먹으면", "먹다"));
+        assert!(has("먹은", "먹다"));
+        assert!(has("먹는다", "먹다"));
+        assert!(has("갔다", "가다"));
+        assert!(has("갑니다", "가다"));
+        assert!(has("간다", "가다"));
+        assert_eq!(deconjugate("해요"), vec!["하다"]);
+        assert_eq!(deconjugate("먹었습니다"), vec!["먹다"]);
+        assert!(has("봤어요", "보다"));
+        assert!(has("마셨다", "마시다"));
+    }
+
+    #[test]
+    fn uncontracted() {
+        assert!(has("되었다", "되다"));
+        assert!(has("보았다", "보다"));
+        assert!(has("주었어요", "주다"));
+        assert!(has("두었다", "두다"));
+        assert!(has("마시었다", "마시다"));
+        assert!(has("보아요", "보다"));
+        assert!(has("하여", "하다"));
+        assert!(!has("가아", "가다"));
+    }
+
+    #[test]
+    fn ranking() {
+        assert_eq!(deconjugate("만나요")[0], "만나다");
+        assert_eq!(deconjugate("서요")[0], "서다");
+        assert_eq!(deconjugate("건너요")[0], "건너다");
+        assert_eq!(deconjugate("만들어요")[0], "만들다");
+        assert_eq!(deconjugate("보았다")[0], "보다");
+        assert_eq!(deconjugate("마시었다")[0], "마시다");
+    }
+
+    #[test]
+    fn honorific_and_auxiliary() {
+        assert!(has("가셨어요", "가다"));
+        assert!(has("가세요", "가다"));
+        assert!(has("읽으십니다", "읽다"));
+        assert!(has("도와줘", "돕다"));
+        assert!(has("도와줘", "도와주다"));
+    }
+
+    #[test]
+    fn rieul() {
+        assert!(has("사는", "살다"));
+        assert!(has("삽니다", "살다"));
+        assert!(has("사니까", "살다"));
+        assert!(has("살아요", "살다"));
+    }
+
+    #[test]
+    fn irregular() {
+        assert_eq!(deconjugate("도와요")[0], "돕다");
+        assert_eq!(deconjugate("추워요")[0], "춥다");
+        assert!(has("들었어요", "듣다"));
+        assert!(has("들었어요", "들다"));
+        assert!(has("지어요", "짓다"));
+        assert!(has("파래요", "파랗다"));
+        assert!(has("불렀다", "부르다"));
+        assert!(has("써요", "쓰다"));
+        assert!(has("아팠다", "아프다"));
+        assert!(has("퍼요", "푸다"));
+        assert!(has("추우면", "춥다"));
+        assert!(has("들으니까", "듣다"));
+    }
+
+    #[test]
+    fn not_predicates() {
+        assert!(!has("먹었습니다", "먹었습니다다"));
+        assert_eq!(deconjugate("셨어요"), Vec::<String>::new());
+        assert_eq!(deconjugate("었어"), Vec::<String>::new());
+        assert_eq!(deconjugate("으면"), Vec::<String>::new());
+        assert!(!has("가셨어요", "셔다"));
+        assert_eq!(deconjugate(""), Vec::<String>::new());
+        assert_eq!(deconjugate("run"), Vec::<String>::new());
+    }
+}
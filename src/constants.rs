@@ -3,6 +3,7 @@ pub(crate) const HANGEUL_OFFSET: u32 = 0xAC00;
 pub(crate) const SYLLABLE_START: u32 = 0xAC00;
 pub(crate) const SYLLABLE_END: u32 = 0xD7A3;
 
+pub(crate) const CHOSEONG_COUNT: u32 = 19;
 pub(crate) const JUNGSEONG_COUNT: u32 = 21;
 pub(crate) const JONGSEONG_COUNT: u32 = 28;
 
@@ -0,0 +1,115 @@
+use crate::number::*;
+
+/// The name of a month, e.g. 3 → 삼월, with the irregular 유월 and 시월.
+pub fn spell_month(month: u32) -> Option<String> {
+    let spelled = match month {
+        6 => "유월".to_string(),
+        10 => "시월".to_string(),
+        1..=12 => format!("{}월", sino_korean(month as u64)),
+        _ => return None,
+    };
+    Some(spelled)
+}
+
+/// The number of days in a month of the Gregorian calendar, or `None` for an invalid month.
+fn days_in_month(year: u64, month: u32) -> Option<u32> {
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => Some(31),
+        4 | 6 | 9 | 11 => Some(30),
+        2 if leap => Some(29),
+        2 => Some(28),
+        _ => None,
+    }
+}
+
+/// Spells a date as it is read, e.g. 2026, 10, 18 → 이천이십육 년 시월 십팔 일.
+///
+/// Returns `None` when the month or the day is out of range, e.g. 2026, 2, 29.
+pub fn spell_date(year: u64, month: u32, day: u32) -> Option<String> {
+    if !(1..=days_in_month(year, month)?).contains(&day) {
+        return None;
+    }
+
+    Some(format!(
+        "{} {} {}",
+        count(year, "년"),
+        spell_month(month)?,
+        count(day as u64, "일")
+    ))
+}
+
+/// Spells a time on the 24-hour clock as it is read, with native hours and Sino-Korean minutes,
+/// e.g. 15:15 → 오후 세 시 십오 분.
+///
+/// Returns `None` when the hour or the minute is out of range.
+pub fn spell_time(hour: u32, minute: u32) -> Option<String> {
+    if hour >= 24 || minute >= 60 {
+        return None;
+    }
+
+    let period = if hour < 12 { "오전" } else { "오후" };
+    let hour = match hour % 12 {
+        0 => 12,
+        hour => hour,
+    };
+
+    let mut spelled = format!("{} {}", period, count(hour as u64, "시"));
+    if minute > 0 {
+        spelled.push(' ');
+        spelled.push_str(&count(minute as u64, "분"));
+    }
+    Some(spelled)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn months() {
+        assert_eq!(spell_month(1), Some("일월".to_string()));
+        assert_eq!(spell_month(6), Some("유월".to_string()));
+        assert_eq!(spell_month(10), Some("시월".to_string()));
+        assert_eq!(spell_month(12), Some("십이월".to_string()));
+        assert_eq!(spell_month(13), None);
+    }
+
+    #[test]
+    fn dates() {
+        assert_eq!(
+            spell_date(2026, 10, 18),
+            Some("이천이십육 년 시월 십팔 일".to_string())
+        );
+        assert_eq!(
+            spell_date(2000, 6, 1),
+            Some("이천 년 유월 일 일".to_string())
+        );
+        assert_eq!(spell_date(2026, 0, 1), None);
+        assert_eq!(spell_date(2026, 1, 32), None);
+        assert_eq!(spell_date(2026, 2, 31), None);
+        assert_eq!(spell_date(2026, 4, 31), None);
+        assert_eq!(spell_date(2026, 2, 29), None);
+        assert_eq!(spell_date(2000, 2, 0), None);
+        assert_eq!(
+            spell_date(2024, 2, 29),
+            Some("이천이십사 년 이월 이십구 일".to_string())
+        );
+        assert!(spell_date(2000, 2, 29).is_some());
+        assert_eq!(spell_date(1900, 2, 29), None);
+    }
+
+    #[test]
+    fn times() {
+        assert_eq!(spell_time(15, 15), Some("오후 세 시 십오 분".to_string()));
+        assert_eq!(spell_time(9, 30), Some("오전 아홉 시 삼십 분".to_string()));
+        assert_eq!(spell_time(0, 5), Some("오전 열두 시 오 분".to_string()));
+        assert_eq!(spell_time(12, 0), Some("오후 열두 시".to_string()));
+        assert_eq!(
+            spell_time(23, 59),
+            Some("오후 열한 시 오십구 분".to_string())
+        );
+        assert_eq!(spell_time(24, 0), None);
+        assert_eq!(spell_time(1, 60), None);
+    }
+}
@@ -0,0 +1,144 @@
+use crate::characters::*;
+use crate::choseong::*;
+use crate::jongseong::*;
+use crate::jungseong::*;
+use crate::syllable::*;
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+use std::str::Chars;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum JamoForm {
+    /// U+1100 ~ U+11FF
+    Normal,
+    /// U+3131 ~ U+318E
+    Compat,
+}
+
+#[derive(Clone, Debug)]
+pub struct Decompose<'a> {
+    characters: Chars<'a>,
+    form: JamoForm,
+    pending: VecDeque<char>,
+}
+
+pub fn decompose(text: &str) -> Decompose<'_> {
+    decompose_with(text, JamoForm::Normal)
+}
+
+pub fn decompose_compat(text: &str) -> Decompose<'_> {
+    decompose_with(text, JamoForm::Compat)
+}
+
+pub fn decompose_with(text: &str, form: JamoForm) -> Decompose<'_> {
+    Decompose {
+        characters: text.chars(),
+        form,
+        pending: VecDeque::with_capacity(3),
+    }
+}
+
+impl Syllable {
+    pub fn to_jamo(&self, form: JamoForm) -> (char, char, Option<char>) {
+        let (choseong, jungseong, jongseong): (Choseong, Jungseong, Option<Jongseong>) =
+            self.clone().into();
+        let choseong = ChoseongCharacter::from(choseong);
+        let jungseong = JungseongCharacter::from(jungseong);
+        let jongseong = jongseong.map(JongseongCharacter::from);
+
+        match form {
+            JamoForm::Normal => (
+                choseong.to_normal().into(),
+                jungseong.to_normal().into(),
+                jongseong.map(|character| character.to_normal().into()),
+            ),
+            JamoForm::Compat => (
+                choseong.to_compat().into(),
+                jungseong.to_compat().into(),
+                jongseong.map(|character| character.to_compat().into()),
+            ),
+        }
+    }
+}
+
+impl Iterator for Decompose<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(character) = self.pending.pop_front() {
+            return Some(character);
+        }
+
+        let character = self.characters.next()?;
+        match Syllable::try_from(character) {
+            Ok(syllable) => {
+                let (choseong, jungseong, jongseong) = syllable.to_jamo(self.form);
+                self.pending.push_back(jungseong);
+                self.pending.extend(jongseong);
+
+                Some(choseong)
+            }
+            Err(_) => Some(character),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.characters.size_hint();
+        let pending = self.pending.len();
+
+        (
+            lower + pending,
+            upper.and_then(|upper| upper.checked_mul(3)?.checked_add(pending)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decompose_syllables() {
+        assert_eq!(
+            decompose("한글").collect::<String>(),
+            "\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}"
+        );
+        assert_eq!(
+            decompose("개걔").collect::<String>(),
+            "\u{1100}\u{1162}\u{1100}\u{1164}"
+        );
+    }
+
+    #[test]
+    fn decompose_compat_syllables() {
+        assert_eq!(decompose_compat("한글").collect::<String>(), "ㅎㅏㄴㄱㅡㄹ");
+        assert_eq!(
+            decompose_compat("닭 흙").collect::<String>(),
+            "ㄷㅏㄺ ㅎㅡㄺ"
+        );
+        assert_eq!(decompose_compat("최").collect::<String>(), "ㅊㅚ");
+    }
+
+    #[test]
+    fn decompose_passes_through_non_syllables() {
+        assert_eq!(
+            decompose_compat("Rust 1.0, 안녕!").collect::<String>(),
+            "Rust 1.0, ㅇㅏㄴㄴㅕㅇ!"
+        );
+        assert_eq!(decompose("ㄱㅏ").collect::<String>(), "ㄱㅏ");
+        assert_eq!(decompose("").count(), 0);
+    }
+
+    #[test]
+    fn to_jamo() {
+        let syllable = Syllable::try_from('힣').unwrap();
+        assert_eq!(
+            syllable.to_jamo(JamoForm::Normal),
+            ('\u{1112}', '\u{1175}', Some('\u{11C2}'))
+        );
+        assert_eq!(syllable.to_jamo(JamoForm::Compat), ('ㅎ', 'ㅣ', Some('ㅎ')));
+
+        let syllable = Syllable::try_from('가').unwrap();
+        assert_eq!(syllable.to_jamo(JamoForm::Compat), ('ㄱ', 'ㅏ', None));
+    }
+}
@@ -0,0 +1,187 @@
+use crate::syllable::*;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HangulCategory {
+    Syllable,
+    Choseong,
+    Jungseong,
+    Jongseong,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum HangulError {
+    /// The code point is not a valid `char`.
+    OutOfRange { code: u32, expected: HangulCategory },
+    /// The character is not hangul at all.
+    NotHangul { code: u32, expected: HangulCategory },
+    /// The character is hangul, but not of the expected category.
+    Mismatch { code: u32, expected: HangulCategory },
+}
+
+impl HangulError {
+    pub(crate) fn new(code: u32, expected: HangulCategory) -> Self {
+        if std::char::from_u32(code).is_none() {
+            HangulError::OutOfRange { code, expected }
+        } else if !code.is_hangeul() {
+            HangulError::NotHangul { code, expected }
+        } else {
+            HangulError::Mismatch { code, expected }
+        }
+    }
+
+    pub fn code(&self) -> u32 {
+        match self {
+            HangulError::OutOfRange { code, .. } => *code,
+            HangulError::NotHangul { code, .. } => *code,
+            HangulError::Mismatch { code, .. } => *code,
+        }
+    }
+
+    pub fn expected(&self) -> HangulCategory {
+        match self {
+            HangulError::OutOfRange { expected, .. } => *expected,
+            HangulError::NotHangul { expected, .. } => *expected,
+            HangulError::Mismatch { expected, .. } => *expected,
+        }
+    }
+}
+
+impl fmt::Display for HangulCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            HangulCategory::Syllable => "syllable",
+            HangulCategory::Choseong => "choseong",
+            HangulCategory::Jungseong => "jungseong",
+            HangulCategory::Jongseong => "jongseong",
+        };
+
+        f.write_str(name)
+    }
+}
+
+impl fmt::Display for HangulError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HangulError::OutOfRange { code, expected } => write!(
+                f,
+                "code point {:#X} is out of range, expected {}",
+                code, expected
+            ),
+            HangulError::NotHangul { code, expected } => {
+                write!(f, "U+{:04X} is not hangul, expected {}", code, expected)
+            }
+            HangulError::Mismatch { code, expected } => {
+                write!(f, "U+{:04X} is not a {}", code, expected)
+            }
+        }
+    }
+}
+
+impl Error for HangulError {}
+
+/// Errors from reading a number written in Korean. Positions count characters.
+#[derive(Clone, Debug, PartialEq)]
+pub enum NumberError {
+    /// There is no number to read.
+    Empty,
+    /// The character is not part of a number.
+    UnexpectedCharacter { position: usize, character: char },
+    /// A numeral or unit is out of order, e.g. 삼사 or 백천.
+    Misplaced { position: usize, word: String },
+    /// The number does not fit the requested type.
+    Overflow,
+}
+
+impl fmt::Display for NumberError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NumberError::Empty => f.write_str("no number to read"),
+            NumberError::UnexpectedCharacter {
+                position,
+                character,
+            } => write!(
+                f,
+                "unexpected character {:?} at position {}",
+                character, position
+            ),
+            NumberError::Misplaced { position, word } => {
+                write!(f, "{:?} at position {} is out of order", word, position)
+            }
+            NumberError::Overflow => f.write_str("number is too large"),
+        }
+    }
+}
+
+impl Error for NumberError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::choseong::*;
+    use crate::jongseong::*;
+    use crate::jungseong::*;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn out_of_range() {
+        let error = Syllable::try_from(0x110000).unwrap_err();
+        assert_eq!(
+            error,
+            HangulError::OutOfRange {
+                code: 0x110000,
+                expected: HangulCategory::Syllable,
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "code point 0x110000 is out of range, expected syllable"
+        );
+    }
+
+    #[test]
+    fn not_hangul() {
+        let error = Choseong::try_from('A').unwrap_err();
+        assert_eq!(
+            error,
+            HangulError::NotHangul {
+                code: 0x41,
+                expected: HangulCategory::Choseong,
+            }
+        );
+        assert_eq!(error.to_string(), "U+0041 is not hangul, expected choseong");
+    }
+
+    #[test]
+    fn mismatch() {
+        let error = Jungseong::try_from('ㄱ').unwrap_err();
+        assert_eq!(
+            error,
+            HangulError::Mismatch {
+                code: 0x3131,
+                expected: HangulCategory::Jungseong,
+            }
+        );
+        assert_eq!(error.to_string(), "U+3131 is not a jungseong");
+
+        let error = Jongseong::try_from('가').unwrap_err();
+        assert_eq!(error.code(), 0xAC00);
+        assert_eq!(error.expected(), HangulCategory::Jongseong);
+
+        let error = Syllable::try_from('ㅏ').unwrap_err();
+        assert_eq!(error.expected(), HangulCategory::Syllable);
+    }
+
+    #[test]
+    fn composition() {
+        let error = Syllable::try_from(('ㄱ', 'ㄴ', None)).unwrap_err();
+        assert_eq!(
+            error,
+            HangulError::Mismatch {
+                code: 0x3134,
+                expected: HangulCategory::Jungseong,
+            }
+        );
+    }
+}
@@ -0,0 +1,110 @@
+use super::dubeolsik::*;
+use super::*;
+use crate::choseong::*;
+use crate::jongseong::*;
+use crate::jungseong::*;
+use std::convert::TryFrom;
+
+const KEYS: &str = "qwertyuiopasdfghjklzxcvbnmQWERTOP";
+
+fn jamo_to_key(jamo: &Jamo) -> Option<char> {
+    KEYS.chars()
+        .find(|key| key_to_jamo(*key).as_ref() == Some(jamo))
+}
+
+fn push_choseong(keys: &mut String, character: &ChoseongCharacter) {
+    keys.extend(jamo_to_key(&Jamo::Consonant(character.clone())));
+}
+
+fn push_jungseong(keys: &mut String, character: &JungseongCharacter) {
+    match character.split() {
+        Some((first, second)) => {
+            push_jungseong(keys, &first);
+            push_jungseong(keys, &second);
+        }
+        None => keys.extend(jamo_to_key(&Jamo::Vowel(character.clone()))),
+    }
+}
+
+fn push_jongseong(keys: &mut String, character: &JongseongCharacter) {
+    match character.split() {
+        Some((first, second)) => {
+            push_jongseong(keys, &first);
+            push_jongseong(keys, &second);
+        }
+        None => {
+            if let Some(choseong) = character.to_choseong() {
+                push_choseong(keys, &choseong);
+            }
+        }
+    }
+}
+
+/// Reads text typed on a QWERTY layout as 2-set keystrokes, e.g. `dkssudgktpdy` → 안녕하세요.
+pub fn qwerty_to_hangul(text: &str) -> String {
+    let mut ime = Dubeolsik::new();
+    let mut hangul: String = text.chars().map(|key| ime.key(key).committed).collect();
+    hangul.push_str(&ime.flush().committed);
+    hangul
+}
+
+/// The QWERTY keys that type hangul on the 2-set layout, e.g. `ㅗ디ㅣㅐ` → hello.
+pub fn hangul_to_qwerty(text: &str) -> String {
+    let mut keys = String::new();
+
+    for character in text.chars() {
+        if let Ok(syllable) = Syllable::try_from(character) {
+            let (choseong, jungseong, jongseong): (
+                ChoseongCharacter,
+                JungseongCharacter,
+                Option<JongseongCharacter>,
+            ) = syllable.into();
+            push_choseong(&mut keys, &choseong);
+            push_jungseong(&mut keys, &jungseong);
+            if let Some(jongseong) = &jongseong {
+                push_jongseong(&mut keys, jongseong);
+            }
+        } else if !character.is_jamo() {
+            keys.push(character);
+        } else if let Ok(choseong) = Choseong::try_from(character) {
+            push_choseong(&mut keys, &choseong.into());
+        } else if let Ok(jungseong) = Jungseong::try_from(character) {
+            push_jungseong(&mut keys, &jungseong.into());
+        } else if let Ok(jongseong) = Jongseong::try_from(character) {
+            push_jongseong(&mut keys, &jongseong.into());
+        } else {
+            keys.push(character);
+        }
+    }
+
+    keys
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_hangul() {
+        assert_eq!(qwerty_to_hangul("dkssudgktpdy"), "안녕하세요");
+        assert_eq!(qwerty_to_hangul("gksrnr ekfr"), "한국 닭");
+        assert_eq!(qwerty_to_hangul("rhkdlf!"), "과일!");
+        assert_eq!(qwerty_to_hangul("Tkd"), "쌍");
+    }
+
+    #[test]
+    fn to_qwerty() {
+        assert_eq!(hangul_to_qwerty("ㅗ디ㅣㅐ"), "hello");
+        assert_eq!(hangul_to_qwerty("안녕하세요"), "dkssudgktpdy");
+        assert_eq!(hangul_to_qwerty("닭 과일"), "ekfr rhkdlf");
+        assert_eq!(hangul_to_qwerty("쌍ㅒ"), "TkdO");
+        assert_eq!(hangul_to_qwerty("ㄳ 123"), "rt 123");
+    }
+
+    #[test]
+    fn round_trip() {
+        for text in &["안녕하세요", "닭고기", "값어치", "의사"] {
+            assert_eq!(qwerty_to_hangul(&hangul_to_qwerty(text)), *text);
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use super::*;
+
+/// A jamo on the 2-set layout. Consonants become either choseong or jongseong.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Jamo {
+    Consonant(ChoseongCharacter),
+    Vowel(JungseongCharacter),
+}
+
+/// The jamo typed with a QWERTY key. Shift only matters for the doubled consonants, ㅒ and ㅖ.
+pub(crate) fn key_to_jamo(key: char) -> Option<Jamo> {
+    let jamo = match key {
+        'Q' => Jamo::Consonant(ChoseongCharacter::SsangBieup),
+        'W' => Jamo::Consonant(ChoseongCharacter::SsangJieut),
+        'E' => Jamo::Consonant(ChoseongCharacter::SsangDigeut),
+        'R' => Jamo::Consonant(ChoseongCharacter::SsangGiyeok),
+        'T' => Jamo::Consonant(ChoseongCharacter::SsangSiot),
+        'O' => Jamo::Vowel(JungseongCharacter::YAE),
+        'P' => Jamo::Vowel(JungseongCharacter::YE),
+        'q' => Jamo::Consonant(ChoseongCharacter::Bieup),
+        'w' => Jamo::Consonant(ChoseongCharacter::Jieut),
+        'e' => Jamo::Consonant(ChoseongCharacter::Digeut),
+        'r' => Jamo::Consonant(ChoseongCharacter::Giyeok),
+        't' => Jamo::Consonant(ChoseongCharacter::Siot),
+        'y' => Jamo::Vowel(JungseongCharacter::YO),
+        'u' => Jamo::Vowel(JungseongCharacter::YEO),
+        'i' => Jamo::Vowel(JungseongCharacter::YA),
+        'o' => Jamo::Vowel(JungseongCharacter::AE),
+        'p' => Jamo::Vowel(JungseongCharacter::E),
+        'a' => Jamo::Consonant(ChoseongCharacter::Mieum),
+        's' => Jamo::Consonant(ChoseongCharacter::Nieun),
+        'd' => Jamo::Consonant(ChoseongCharacter::Ieung),
+        'f' => Jamo::Consonant(ChoseongCharacter::Rieul),
+        'g' => Jamo::Consonant(ChoseongCharacter::Hieuh),
+        'h' => Jamo::Vowel(JungseongCharacter::O),
+        'j' => Jamo::Vowel(JungseongCharacter::EO),
+        'k' => Jamo::Vowel(JungseongCharacter::A),
+        'l' => Jamo::Vowel(JungseongCharacter::I),
+        'z' => Jamo::Consonant(ChoseongCharacter::Kiyeok),
+        'x' => Jamo::Consonant(ChoseongCharacter::Tieut),
+        'c' => Jamo::Consonant(ChoseongCharacter::Chieut),
+        'v' => Jamo::Consonant(ChoseongCharacter::Pieup),
+        'b' => Jamo::Vowel(JungseongCharacter::YU),
+        'n' => Jamo::Vowel(JungseongCharacter::U),
+        'm' => Jamo::Vowel(JungseongCharacter::EU),
+        'A'..='Z' => return key_to_jamo(key.to_ascii_lowercase()),
+        _ => return None,
+    };
+    Some(jamo)
+}
+
+/// 두벌식, the standard 2-set layout.
+///
+/// See:
+/// * https://en.wikipedia.org/wiki/Keyboard_layout#Dubeolsik
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Dubeolsik {
+    composer: Composer,
+}
+
+impl Dubeolsik {
+    pub fn new() -> Self {
+        Dubeolsik {
+            composer: Composer::new(),
+        }
+    }
+}
+
+impl InputMethod for Dubeolsik {
+    fn key(&mut self, key: char) -> ImeOutput {
+        match key_to_jamo(key) {
+            Some(Jamo::Consonant(character)) => self.composer.choseong(character, true),
+            Some(Jamo::Vowel(character)) => self.composer.jungseong(character, true),
+            None => self.composer.other(key),
+        }
+    }
+
+    fn backspace(&mut self) -> Option<ImeOutput> {
+        self.composer.backspace()
+    }
+
+    fn flush(&mut self) -> ImeOutput {
+        self.composer.flush()
+    }
+
+    fn preedit(&self) -> String {
+        self.composer.preedit()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type_keys(ime: &mut Dubeolsik, keys: &str) -> (String, String) {
+        let committed = keys.chars().map(|key| ime.key(key).committed).collect();
+        (committed, ime.preedit())
+    }
+
+    fn typed(keys: &str) -> String {
+        let mut ime = Dubeolsik::new();
+        let (mut committed, _) = type_keys(&mut ime, keys);
+        committed.push_str(&ime.flush().committed);
+        committed
+    }
+
+    #[test]
+    fn syllables() {
+        assert_eq!(typed("dkssudgktpdy"), "안녕하세요");
+        assert_eq!(typed("gksrmf"), "한글");
+        assert_eq!(typed("Rkcl"), "까치");
+        assert_eq!(typed("dkdk 123"), "아아 123");
+    }
+
+    #[test]
+    fn double_jongseong() {
+        let mut ime = Dubeolsik::new();
+        assert_eq!(
+            type_keys(&mut ime, "ekfr"),
+            ("".to_string(), "닭".to_string())
+        );
+        assert_eq!(
+            ime.key('k'),
+            ImeOutput {
+                committed: "달".to_string(),
+                preedit: "가".to_string(),
+            }
+        );
+        assert_eq!(typed("rkqt"), "값");
+        assert_eq!(typed("rkqtl"), "갑시");
+        assert_eq!(typed("dlfr"), "읽");
+    }
+
+    #[test]
+    fn compound_jungseong() {
+        assert_eq!(typed("rhk"), "과");
+        assert_eq!(typed("dnjs"), "원");
+        assert_eq!(typed("dml"), "의");
+        assert_eq!(typed("hk"), "ㅘ");
+        assert_eq!(typed("kk"), "ㅏㅏ");
+    }
+
+    #[test]
+    fn orphan_jamo() {
+        assert_eq!(typed("rr"), "ㄱㄱ");
+        assert_eq!(typed("rkE"), "가ㄸ");
+        assert_eq!(typed("rkEk"), "가따");
+    }
+
+    #[test]
+    fn backspace() {
+        let mut ime = Dubeolsik::new();
+        type_keys(&mut ime, "ekfr");
+        let preedits: Vec<String> = std::iter::from_fn(|| ime.backspace())
+            .map(|output| output.preedit)
+            .collect();
+        assert_eq!(preedits, vec!["달", "다", "ㄷ", ""]);
+        assert_eq!(ime.backspace(), None);
+
+        let mut ime = Dubeolsik::new();
+        type_keys(&mut ime, "ekfrk");
+        assert_eq!(ime.backspace().unwrap().preedit, "ㄱ");
+        assert_eq!(ime.backspace().unwrap().preedit, "");
+        assert_eq!(ime.backspace(), None);
+
+        let mut ime = Dubeolsik::new();
+        type_keys(&mut ime, "rhk");
+        assert_eq!(ime.backspace().unwrap().preedit, "고");
+    }
+}
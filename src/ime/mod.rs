@@ -0,0 +1,261 @@
+pub mod convert;
+pub mod dubeolsik;
+pub mod sebeolsik;
+
+pub use convert::*;
+pub use dubeolsik::*;
+pub use sebeolsik::*;
+
+use crate::characters::*;
+use crate::syllable::*;
+
+/// Text produced by a keystroke. `preedit` is the syllable still being composed.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ImeOutput {
+    pub committed: String,
+    pub preedit: String,
+}
+
+pub trait InputMethod {
+    /// Handles a keystroke. Keys outside the layout commit the preedit and themselves.
+    fn key(&mut self, key: char) -> ImeOutput;
+    /// Removes the last typed jamo, or returns `None` when nothing is being composed.
+    fn backspace(&mut self) -> Option<ImeOutput>;
+    /// Commits the syllable being composed.
+    fn flush(&mut self) -> ImeOutput;
+    fn preedit(&self) -> String;
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Preedit {
+    choseong: Option<ChoseongCharacter>,
+    jungseong: Option<JungseongCharacter>,
+    jongseong: Option<JongseongCharacter>,
+}
+
+impl Preedit {
+    fn is_empty(&self) -> bool {
+        self.choseong.is_none() && self.jungseong.is_none() && self.jongseong.is_none()
+    }
+
+    /// A syllable once it has a choseong and a jungseong, otherwise compatibility jamo.
+    fn to_text(&self) -> String {
+        match self {
+            Preedit {
+                choseong: Some(choseong),
+                jungseong: Some(jungseong),
+                jongseong,
+            } => {
+                let syllable: Syllable =
+                    (choseong.clone(), jungseong.clone(), jongseong.clone()).into();
+                char::from(syllable).to_string()
+            }
+            Preedit {
+                choseong,
+                jungseong,
+                jongseong,
+            } => {
+                let mut text = String::new();
+                if let Some(choseong) = choseong {
+                    text.push(choseong.to_compat().into());
+                }
+                if let Some(jungseong) = jungseong {
+                    text.push(jungseong.to_compat().into());
+                }
+                if let Some(jongseong) = jongseong {
+                    text.push(jongseong.to_compat().into());
+                }
+                text
+            }
+        }
+    }
+}
+
+/// Assembles jamo into a syllable, keeping each state for jamo-level backspace.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct Composer {
+    preedit: Preedit,
+    history: Vec<Preedit>,
+}
+
+impl Composer {
+    pub(crate) fn new() -> Self {
+        Composer::default()
+    }
+
+    fn update(&mut self, preedit: Preedit) {
+        let previous = std::mem::replace(&mut self.preedit, preedit);
+        self.history.push(previous);
+    }
+
+    /// Commits the preedit and starts over from `preedit`.
+    fn restart(&mut self, preedit: Preedit, history: Vec<Preedit>) -> String {
+        let committed = self.preedit.to_text();
+        self.preedit = preedit;
+        self.history = history;
+        committed
+    }
+
+    fn output(&self, committed: String) -> ImeOutput {
+        ImeOutput {
+            committed,
+            preedit: self.preedit.to_text(),
+        }
+    }
+
+    /// A consonant, which becomes a jongseong when `as_jongseong` and the syllable allows it.
+    /// Otherwise, as on 3-set layouts, typing a choseong twice doubles it, e.g. ㄱ + ㄱ → ㄲ.
+    pub(crate) fn choseong(
+        &mut self,
+        character: ChoseongCharacter,
+        as_jongseong: bool,
+    ) -> ImeOutput {
+        let preedit = self.preedit.clone();
+        if let (false, Some(previous), None) = (as_jongseong, &preedit.choseong, &preedit.jungseong)
+        {
+            if let Some(choseong) = previous.combine(&character) {
+                self.update(Preedit {
+                    choseong: Some(choseong),
+                    ..preedit
+                });
+                return self.output(String::new());
+            }
+        }
+        if as_jongseong && preedit.choseong.is_some() && preedit.jungseong.is_some() {
+            let jongseong = match (&preedit.jongseong, character.to_jongseong()) {
+                (None, Some(jongseong)) => Some(jongseong),
+                (Some(previous), Some(jongseong)) => previous.combine(&jongseong),
+                (_, None) => None,
+            };
+            if jongseong.is_some() {
+                self.update(Preedit {
+                    jongseong,
+                    ..preedit
+                });
+                return self.output(String::new());
+            }
+        }
+
+        let next = Preedit {
+            choseong: Some(character),
+            ..Preedit::default()
+        };
+        if preedit.is_empty() {
+            self.update(next);
+            return self.output(String::new());
+        }
+        let committed = self.restart(next, vec![Preedit::default()]);
+        self.output(committed)
+    }
+
+    /// A vowel. With `move_jongseong` the jongseong moves over to a new syllable,
+    /// e.g. 닭 + ㅏ → 달가.
+    pub(crate) fn jungseong(
+        &mut self,
+        character: JungseongCharacter,
+        move_jongseong: bool,
+    ) -> ImeOutput {
+        let preedit = self.preedit.clone();
+
+        if let Some(jongseong) = &preedit.jongseong {
+            let (kept, moved) = match jongseong.split() {
+                Some((kept, moved)) => (Some(kept), moved),
+                None => (None, jongseong.clone()),
+            };
+            if let (true, Some(_), Some(choseong)) =
+                (move_jongseong, &preedit.choseong, moved.to_choseong())
+            {
+                self.preedit.jongseong = kept;
+                let initial = Preedit {
+                    choseong: Some(choseong),
+                    ..Preedit::default()
+                };
+                let next = Preedit {
+                    jungseong: Some(character),
+                    ..initial.clone()
+                };
+                let committed = self.restart(next, vec![Preedit::default(), initial]);
+                return self.output(committed);
+            }
+        } else if preedit.jungseong.is_none() {
+            self.update(Preedit {
+                jungseong: Some(character),
+                ..preedit
+            });
+            return self.output(String::new());
+        } else if let Some(jungseong) = preedit
+            .jungseong
+            .as_ref()
+            .and_then(|jungseong| jungseong.combine(&character))
+        {
+            self.update(Preedit {
+                jungseong: Some(jungseong),
+                ..preedit
+            });
+            return self.output(String::new());
+        }
+
+        let next = Preedit {
+            jungseong: Some(character),
+            ..Preedit::default()
+        };
+        let committed = self.restart(next, vec![Preedit::default()]);
+        self.output(committed)
+    }
+
+    /// A jongseong typed with its own key. Typing ㄱ or ㅅ twice doubles it.
+    pub(crate) fn jongseong(&mut self, character: JongseongCharacter) -> ImeOutput {
+        let preedit = self.preedit.clone();
+        let jongseong = match (&preedit.jungseong, &preedit.jongseong) {
+            (Some(_), None) => Some(character.clone()),
+            (_, Some(JongseongCharacter::Giyeok)) if character == JongseongCharacter::Giyeok => {
+                Some(JongseongCharacter::SsangGiyeok)
+            }
+            (_, Some(JongseongCharacter::Siot)) if character == JongseongCharacter::Siot => {
+                Some(JongseongCharacter::SsangSiot)
+            }
+            (_, Some(previous)) => previous.combine(&character),
+            (None, None) => None,
+        };
+        if jongseong.is_some() {
+            self.update(Preedit {
+                jongseong,
+                ..preedit
+            });
+            return self.output(String::new());
+        }
+
+        let next = Preedit {
+            jongseong: Some(character),
+            ..Preedit::default()
+        };
+        if preedit.is_empty() {
+            self.update(next);
+            return self.output(String::new());
+        }
+        let committed = self.restart(next, vec![Preedit::default()]);
+        self.output(committed)
+    }
+
+    pub(crate) fn backspace(&mut self) -> Option<ImeOutput> {
+        let previous = self.history.pop()?;
+        self.preedit = previous;
+        Some(self.output(String::new()))
+    }
+
+    pub(crate) fn flush(&mut self) -> ImeOutput {
+        let committed = self.restart(Preedit::default(), vec![]);
+        self.output(committed)
+    }
+
+    /// Commits the preedit followed by a character outside the layout.
+    pub(crate) fn other(&mut self, character: char) -> ImeOutput {
+        let mut output = self.flush();
+        output.committed.push(character);
+        output
+    }
+
+    pub(crate) fn preedit(&self) -> String {
+        self.preedit.to_text()
+    }
+}
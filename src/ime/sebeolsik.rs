@@ -0,0 +1,258 @@
+use super::*;
+use crate::choseong::*;
+use crate::jongseong::*;
+use crate::jungseong::*;
+use std::convert::TryFrom;
+
+/// See:
+/// * https://en.wikipedia.org/wiki/Keyboard_layout#Sebeolsik
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SebeolsikLayout {
+    /// 세벌식 최종, with every compound jongseong on the shifted keys.
+    Final,
+    /// 세벌식 390, with digits on the shifted keys of the right hand.
+    Layout390,
+}
+
+/// The character typed with a QWERTY key, as a normal jamo when it is one.
+fn key_to_character(layout: SebeolsikLayout, key: char) -> char {
+    let shifted = match layout {
+        SebeolsikLayout::Final => match key {
+            '!' => Some('\u{11A9}'),
+            '"' => Some('\u{00B7}'),
+            '#' => Some('\u{11BD}'),
+            '$' => Some('\u{11B5}'),
+            '%' => Some('\u{11B4}'),
+            '@' => Some('\u{11B0}'),
+            'A' => Some('\u{11AE}'),
+            'C' => Some('\u{11BF}'),
+            'D' => Some('\u{11B2}'),
+            'E' => Some('\u{11AC}'),
+            'F' => Some('\u{11B1}'),
+            'G' => Some('\u{1164}'),
+            'Q' => Some('\u{11C1}'),
+            'R' => Some('\u{11B6}'),
+            'S' => Some('\u{11AD}'),
+            'T' => Some('\u{11B3}'),
+            'V' => Some('\u{11AA}'),
+            'W' => Some('\u{11C0}'),
+            'X' => Some('\u{11B9}'),
+            'Z' => Some('\u{11BE}'),
+            'B' => Some('?'),
+            'H' => Some('0'),
+            'I' => Some('7'),
+            'J' => Some('1'),
+            'K' => Some('2'),
+            'L' => Some('3'),
+            'M' => Some('"'),
+            'N' => Some('-'),
+            'O' => Some('8'),
+            'P' => Some('9'),
+            'U' => Some('6'),
+            'Y' => Some('5'),
+            ':' => Some('4'),
+            '<' => Some(','),
+            '>' => Some('.'),
+            '?' => Some('!'),
+            _ => None,
+        },
+        SebeolsikLayout::Layout390 => match key {
+            '!' => Some('\u{11BD}'),
+            'A' => Some('\u{11AE}'),
+            'C' => Some('\u{11B1}'),
+            'D' => Some('\u{11B0}'),
+            'E' => Some('\u{11BF}'),
+            'F' => Some('\u{11A9}'),
+            'Q' => Some('\u{11C1}'),
+            'R' => Some('\u{1164}'),
+            'S' => Some('\u{11AD}'),
+            'V' => Some('\u{11B6}'),
+            'W' => Some('\u{11C0}'),
+            'X' => Some('\u{11B9}'),
+            'Z' => Some('\u{11BE}'),
+            'B' => Some('!'),
+            'G' => Some('/'),
+            'H' => Some('\''),
+            'I' => Some('8'),
+            'J' => Some('4'),
+            'K' => Some('5'),
+            'L' => Some('6'),
+            'M' => Some('1'),
+            'N' => Some('0'),
+            'O' => Some('9'),
+            'P' => Some('>'),
+            'T' => Some(';'),
+            'U' => Some('7'),
+            'Y' => Some('<'),
+            ':' => Some('4'),
+            '<' => Some('2'),
+            '>' => Some('3'),
+            _ => None,
+        },
+    };
+    if let Some(character) = shifted {
+        return character;
+    }
+
+    match key {
+        '1' => '\u{11C2}',
+        '2' => '\u{11BB}',
+        '3' => '\u{11B8}',
+        '4' => '\u{116D}',
+        '5' => '\u{1172}',
+        '6' => '\u{1163}',
+        '7' => '\u{1168}',
+        '8' => '\u{1174}',
+        '9' => '\u{116E}',
+        '0' => '\u{110F}',
+        'q' => '\u{11BA}',
+        'w' => '\u{11AF}',
+        'e' => '\u{1167}',
+        'r' => '\u{1162}',
+        't' => '\u{1165}',
+        'y' => '\u{1105}',
+        'u' => '\u{1103}',
+        'i' => '\u{1106}',
+        'o' => '\u{110E}',
+        'p' => '\u{1111}',
+        'a' => '\u{11BC}',
+        's' => '\u{11AB}',
+        'd' => '\u{1175}',
+        'f' => '\u{1161}',
+        'g' => '\u{1173}',
+        'h' => '\u{1102}',
+        'j' => '\u{110B}',
+        'k' => '\u{1100}',
+        'l' => '\u{110C}',
+        ';' => '\u{1107}',
+        '\'' => '\u{1110}',
+        'z' => '\u{11B7}',
+        'x' => '\u{11A8}',
+        'c' => '\u{1166}',
+        'v' => '\u{1169}',
+        'b' => '\u{116E}',
+        'n' => '\u{1109}',
+        'm' => '\u{1112}',
+        '/' => '\u{1169}',
+        _ => key,
+    }
+}
+
+/// 세벌식, the 3-set layouts with separate keys for choseong, jungseong and jongseong.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sebeolsik {
+    layout: SebeolsikLayout,
+    composer: Composer,
+}
+
+impl Sebeolsik {
+    pub fn new(layout: SebeolsikLayout) -> Self {
+        Sebeolsik {
+            layout,
+            composer: Composer::new(),
+        }
+    }
+
+    pub fn layout(&self) -> SebeolsikLayout {
+        self.layout
+    }
+}
+
+impl InputMethod for Sebeolsik {
+    fn key(&mut self, key: char) -> ImeOutput {
+        let character = key_to_character(self.layout, key);
+        if !character.is_normal_jamo() {
+            return self.composer.other(character);
+        }
+
+        if let Ok(choseong) = Choseong::try_from(character) {
+            self.composer.choseong(choseong.into(), false)
+        } else if let Ok(jungseong) = Jungseong::try_from(character) {
+            self.composer.jungseong(jungseong.into(), false)
+        } else if let Ok(jongseong) = Jongseong::try_from(character) {
+            self.composer.jongseong(jongseong.into())
+        } else {
+            self.composer.other(character)
+        }
+    }
+
+    fn backspace(&mut self) -> Option<ImeOutput> {
+        self.composer.backspace()
+    }
+
+    fn flush(&mut self) -> ImeOutput {
+        self.composer.flush()
+    }
+
+    fn preedit(&self) -> String {
+        self.composer.preedit()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn typed(layout: SebeolsikLayout, keys: &str) -> String {
+        let mut ime = Sebeolsik::new(layout);
+        let mut committed: String = keys.chars().map(|key| ime.key(key).committed).collect();
+        committed.push_str(&ime.flush().committed);
+        committed
+    }
+
+    #[test]
+    fn syllables() {
+        for layout in &[SebeolsikLayout::Final, SebeolsikLayout::Layout390] {
+            assert_eq!(typed(*layout, "jfs"), "안");
+            assert_eq!(typed(*layout, "jfshea"), "안녕");
+            assert_eq!(typed(*layout, "mfskgw"), "한글");
+            assert_eq!(typed(*layout, "kfkf"), "가가");
+            assert_eq!(typed(*layout, "jf jf"), "아 아");
+        }
+    }
+
+    #[test]
+    fn doubled_and_compound() {
+        let layout = SebeolsikLayout::Final;
+        assert_eq!(typed(layout, "kkf"), "까");
+        assert_eq!(typed(layout, "nnf"), "싸");
+        assert_eq!(typed(layout, "kkfxx"), "깎");
+        assert_eq!(typed(layout, "ufwx"), "닭");
+        assert_eq!(typed(layout, "ufwxjf"), "닭아");
+        assert_eq!(typed(layout, "kvf"), "과");
+        assert_eq!(typed(layout, "k/f"), "과");
+        assert_eq!(typed(layout, "k9t"), "궈");
+    }
+
+    #[test]
+    fn shifted_keys() {
+        assert_eq!(typed(SebeolsikLayout::Final, "ufF"), "닮");
+        assert_eq!(typed(SebeolsikLayout::Layout390, "ufC"), "닮");
+        assert_eq!(typed(SebeolsikLayout::Final, "jfX"), "앖");
+        assert_eq!(typed(SebeolsikLayout::Final, "J"), "1");
+        assert_eq!(typed(SebeolsikLayout::Layout390, "M"), "1");
+        assert_eq!(typed(SebeolsikLayout::Final, "jG"), "얘");
+        assert_eq!(typed(SebeolsikLayout::Layout390, "jR"), "얘");
+    }
+
+    #[test]
+    fn orphan_jamo() {
+        let layout = SebeolsikLayout::Final;
+        assert_eq!(typed(layout, "s"), "ㄴ");
+        assert_eq!(typed(layout, "fs"), "ㅏㄴ");
+        assert_eq!(typed(layout, "kfsa"), "간ㅇ");
+    }
+
+    #[test]
+    fn backspace() {
+        let mut ime = Sebeolsik::new(SebeolsikLayout::Final);
+        for key in "ufwx".chars() {
+            ime.key(key);
+        }
+        let preedits: Vec<String> = std::iter::from_fn(|| ime.backspace())
+            .map(|output| output.preedit)
+            .collect();
+        assert_eq!(preedits, vec!["달", "다", "ㄷ", ""]);
+        assert_eq!(ime.backspace(), None);
+    }
+}
@@ -0,0 +1,198 @@
+use crate::characters::*;
+use crate::pronunciation::*;
+use crate::syllable::*;
+use std::convert::TryFrom;
+
+/// What comes before a choseong, which decides its allophone.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Context {
+    /// The start of a word.
+    Initial,
+    /// A vowel, ㄴ, ㅁ or ㅇ.
+    Voiced,
+    /// A final ㄹ.
+    Rieul,
+    /// An obstruent final.
+    Voiceless,
+}
+
+fn is_palatal(jungseong: &JungseongCharacter) -> bool {
+    matches!(
+        jungseong,
+        JungseongCharacter::I
+            | JungseongCharacter::YA
+            | JungseongCharacter::YAE
+            | JungseongCharacter::YEO
+            | JungseongCharacter::YE
+            | JungseongCharacter::YO
+            | JungseongCharacter::YU
+            | JungseongCharacter::WI
+    )
+}
+
+/// ㄱ, ㄷ, ㅂ and ㅈ are voiced and ㅎ is breathy between voiced sounds,
+/// ㄹ is a flap except after another ㄹ, and ㅅ is palatalized before i and y.
+fn choseong_ipa(
+    character: &ChoseongCharacter,
+    jungseong: &JungseongCharacter,
+    context: Context,
+) -> &'static str {
+    let voiced = matches!(context, Context::Voiced | Context::Rieul);
+    let palatal = is_palatal(jungseong);
+
+    match character {
+        ChoseongCharacter::Giyeok if voiced => "ɡ",
+        ChoseongCharacter::Giyeok => "k",
+        ChoseongCharacter::SsangGiyeok => "k͈",
+        ChoseongCharacter::Kiyeok => "kʰ",
+        ChoseongCharacter::Digeut if voiced => "d",
+        ChoseongCharacter::Digeut => "t",
+        ChoseongCharacter::SsangDigeut => "t͈",
+        ChoseongCharacter::Tieut => "tʰ",
+        ChoseongCharacter::Bieup if voiced => "b",
+        ChoseongCharacter::Bieup => "p",
+        ChoseongCharacter::SsangBieup => "p͈",
+        ChoseongCharacter::Pieup => "pʰ",
+        ChoseongCharacter::Jieut if voiced => "d͡ʑ",
+        ChoseongCharacter::Jieut => "t͡ɕ",
+        ChoseongCharacter::SsangJieut => "t͡ɕ͈",
+        ChoseongCharacter::Chieut => "t͡ɕʰ",
+        ChoseongCharacter::Siot if palatal => "ɕʰ",
+        ChoseongCharacter::Siot => "sʰ",
+        ChoseongCharacter::SsangSiot if palatal => "ɕ͈",
+        ChoseongCharacter::SsangSiot => "s͈",
+        ChoseongCharacter::Nieun => "n",
+        ChoseongCharacter::Mieum => "m",
+        ChoseongCharacter::Rieul if context == Context::Rieul => "l",
+        ChoseongCharacter::Rieul => "ɾ",
+        ChoseongCharacter::Hieuh if voiced => "ɦ",
+        ChoseongCharacter::Hieuh => "h",
+        ChoseongCharacter::Ieung => "",
+    }
+}
+
+fn jungseong_ipa(character: &JungseongCharacter) -> &'static str {
+    match character {
+        JungseongCharacter::A => "a",
+        JungseongCharacter::AE => "ɛ",
+        JungseongCharacter::YA => "ja",
+        JungseongCharacter::YAE => "jɛ",
+        JungseongCharacter::EO => "ʌ",
+        JungseongCharacter::E => "e",
+        JungseongCharacter::YEO => "jʌ",
+        JungseongCharacter::YE => "je",
+        JungseongCharacter::O => "o",
+        JungseongCharacter::WA => "wa",
+        JungseongCharacter::WAE => "wɛ",
+        JungseongCharacter::OE => "we",
+        JungseongCharacter::YO => "jo",
+        JungseongCharacter::U => "u",
+        JungseongCharacter::WEO => "wʌ",
+        JungseongCharacter::WE => "we",
+        JungseongCharacter::WI => "ɥi",
+        JungseongCharacter::YU => "ju",
+        JungseongCharacter::EU => "ɯ",
+        JungseongCharacter::YI => "ɰi",
+        JungseongCharacter::I => "i",
+    }
+}
+
+/// Finals are unreleased. They are already neutralized by the pronunciation.
+fn jongseong_ipa(character: &JongseongCharacter) -> &'static str {
+    match character {
+        JongseongCharacter::Giyeok => "k̚",
+        JongseongCharacter::Digeut => "t̚",
+        JongseongCharacter::Bieup => "p̚",
+        JongseongCharacter::Nieun => "n",
+        JongseongCharacter::Rieul => "l",
+        JongseongCharacter::Mieum => "m",
+        _ => "ŋ",
+    }
+}
+
+/// Transcribes hangul into IPA after applying the standard pronunciation,
+/// e.g. 한국어 → hanɡuɡʌ.
+///
+/// Other characters are kept, so words stay separated by their spaces.
+pub fn to_ipa(text: &str) -> String {
+    let mut ipa = String::new();
+    let mut context = Context::Initial;
+
+    for character in pronounce(text).chars() {
+        let syllable = match Syllable::try_from(character) {
+            Ok(syllable) => syllable,
+            Err(_) => {
+                ipa.push(character);
+                context = Context::Initial;
+                continue;
+            }
+        };
+
+        let (choseong, jungseong, jongseong): (
+            ChoseongCharacter,
+            JungseongCharacter,
+            Option<JongseongCharacter>,
+        ) = syllable.into();
+        ipa.push_str(choseong_ipa(&choseong, &jungseong, context));
+        ipa.push_str(jungseong_ipa(&jungseong));
+        if let Some(jongseong) = &jongseong {
+            ipa.push_str(jongseong_ipa(jongseong));
+        }
+
+        context = match jongseong {
+            None
+            | Some(JongseongCharacter::Nieun)
+            | Some(JongseongCharacter::Mieum)
+            | Some(JongseongCharacter::Ieung) => Context::Voiced,
+            Some(JongseongCharacter::Rieul) => Context::Rieul,
+            Some(_) => Context::Voiceless,
+        };
+    }
+
+    ipa
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn voicing() {
+        assert_eq!(to_ipa("부부"), "pubu");
+        assert_eq!(to_ipa("고기"), "koɡi");
+        assert_eq!(to_ipa("바다"), "pada");
+        assert_eq!(to_ipa("감자"), "kamd͡ʑa");
+        assert_eq!(to_ipa("한국어"), "hanɡuɡʌ");
+        assert_eq!(to_ipa("고기 고기"), "koɡi koɡi");
+    }
+
+    #[test]
+    fn rieul() {
+        assert_eq!(to_ipa("나라"), "naɾa");
+        assert_eq!(to_ipa("달"), "tal");
+        assert_eq!(to_ipa("빨리"), "p͈alli");
+        assert_eq!(to_ipa("신라"), "ɕʰilla");
+    }
+
+    #[test]
+    fn tense_and_aspirated() {
+        assert_eq!(to_ipa("까치"), "k͈at͡ɕʰi");
+        assert_eq!(to_ipa("학교"), "hak̚k͈jo");
+        assert_eq!(to_ipa("싸다"), "s͈ada");
+        assert_eq!(to_ipa("좋고"), "t͡ɕokʰo");
+    }
+
+    #[test]
+    fn finals() {
+        assert_eq!(to_ipa("밥"), "pap̚");
+        assert_eq!(to_ipa("옷"), "ot̚");
+        assert_eq!(to_ipa("강"), "kaŋ");
+        assert_eq!(to_ipa("국물"), "kuŋmul");
+    }
+
+    #[test]
+    fn hieuh() {
+        assert_eq!(to_ipa("하늘"), "hanɯl");
+        assert_eq!(to_ipa("전화"), "t͡ɕʌnɦwa");
+    }
+}
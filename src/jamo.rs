@@ -0,0 +1,148 @@
+use crate::characters::*;
+use crate::choseong::*;
+use crate::jongseong::*;
+use crate::jungseong::*;
+use crate::syllable::*;
+use std::convert::TryFrom;
+
+fn to_choseong(character: char) -> Option<ChoseongCharacter> {
+    if character.is_jamo() {
+        Choseong::try_from(character)
+            .ok()
+            .map(ChoseongCharacter::from)
+    } else {
+        None
+    }
+}
+
+fn to_jungseong(character: char) -> Option<JungseongCharacter> {
+    if character.is_jamo() {
+        Jungseong::try_from(character)
+            .ok()
+            .map(JungseongCharacter::from)
+    } else {
+        None
+    }
+}
+
+fn to_jongseong(character: char) -> Option<JongseongCharacter> {
+    if character.is_jamo() {
+        Jongseong::try_from(character)
+            .ok()
+            .map(JongseongCharacter::from)
+    } else {
+        None
+    }
+}
+
+fn is_vowel(character: Option<&char>) -> bool {
+    character
+        .and_then(|character| to_jungseong(*character))
+        .is_some()
+}
+
+/// Converts compatibility jamo (U+3131 ~ U+318E) into conjoining jamo (U+1100 ~ U+11FF),
+/// so that e.g. ㅎㅏㄴ renders as one syllable block.
+///
+/// A consonant after a vowel becomes a jongseong unless a vowel follows it,
+/// and otherwise a choseong. Compound finals such as ㄳ are always jongseong.
+/// Other characters are kept as they are.
+pub fn to_normal_jamo(text: &str) -> String {
+    let characters: Vec<char> = text.chars().collect();
+    let mut converted = String::with_capacity(text.len());
+
+    for (index, character) in characters.iter().enumerate() {
+        if !character.is_compat_jamo() {
+            converted.push(*character);
+            continue;
+        }
+
+        if let Some(jungseong) = to_jungseong(*character) {
+            converted.push(jungseong.to_normal().into());
+            continue;
+        }
+
+        let after_vowel = index > 0 && is_vowel(characters.get(index - 1));
+        let before_vowel = is_vowel(characters.get(index + 1));
+        match (to_choseong(*character), to_jongseong(*character)) {
+            (Some(_), Some(jongseong)) if after_vowel && !before_vowel => {
+                converted.push(jongseong.to_normal().into())
+            }
+            (Some(choseong), _) => converted.push(choseong.to_normal().into()),
+            (None, Some(jongseong)) => converted.push(jongseong.to_normal().into()),
+            (None, None) => converted.push(*character),
+        }
+    }
+    converted
+}
+
+/// Converts conjoining jamo (U+1100 ~ U+11FF) into compatibility jamo (U+3131 ~ U+318E),
+/// where the choseong and jongseong of a consonant become the same character.
+///
+/// Old jamo without a compatibility form and other characters are kept as they are.
+pub fn to_compat_jamo(text: &str) -> String {
+    text.chars()
+        .map(|character| {
+            if !character.is_normal_jamo() {
+                return character;
+            }
+
+            if let Some(choseong) = to_choseong(character) {
+                choseong.to_compat().into()
+            } else if let Some(jungseong) = to_jungseong(character) {
+                jungseong.to_compat().into()
+            } else if let Some(jongseong) = to_jongseong(character) {
+                jongseong.to_compat().into()
+            } else {
+                character
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::normalize::*;
+
+    #[test]
+    fn compat_to_normal() {
+        assert_eq!(to_normal_jamo("ㅎㅏㄴ"), "\u{1112}\u{1161}\u{11AB}");
+        assert_eq!(
+            to_normal_jamo("ㄱㅏㄴㅏ"),
+            "\u{1100}\u{1161}\u{1102}\u{1161}"
+        );
+        assert_eq!(to_normal_jamo("ㄷㅏㄺ"), "\u{1103}\u{1161}\u{11B0}");
+        assert_eq!(to_nfc(&to_normal_jamo("ㅎㅏㄴㄱㅡㄹ")), "한글");
+        assert_eq!(to_nfc(&to_normal_jamo("ㅇㅏㄴㄴㅕㅇ")), "안녕");
+    }
+
+    #[test]
+    fn consonants_without_a_vowel() {
+        assert_eq!(to_normal_jamo("ㄱ"), "\u{1100}");
+        assert_eq!(to_normal_jamo("ㄳ"), "\u{11AA}");
+        assert_eq!(to_normal_jamo("ㄸㅏ"), "\u{1104}\u{1161}");
+        assert_eq!(to_normal_jamo("ㅏㄱ"), "\u{1161}\u{11A8}");
+    }
+
+    #[test]
+    fn other_characters() {
+        assert_eq!(to_normal_jamo("a ㄱ 가"), "a \u{1100} 가");
+        assert_eq!(to_normal_jamo("\u{1100}\u{1161}"), "\u{1100}\u{1161}");
+    }
+
+    #[test]
+    fn normal_to_compat() {
+        assert_eq!(to_compat_jamo("\u{1112}\u{1161}\u{11AB}"), "ㅎㅏㄴ");
+        assert_eq!(to_compat_jamo("\u{11A8}\u{1100}"), "ㄱㄱ");
+        assert_eq!(to_compat_jamo("\u{11AA}"), "ㄳ");
+        assert_eq!(to_compat_jamo("한 ㄱ \u{1140}"), "한 ㄱ \u{1140}");
+        assert_eq!(to_compat_jamo(&to_nfd("한글")), "ㅎㅏㄴㄱㅡㄹ");
+    }
+
+    #[test]
+    fn round_trip() {
+        let text = "ㄷㅏㄹㅏㅁㅈㅜㅣ";
+        assert_eq!(to_compat_jamo(&to_normal_jamo(text)), text);
+    }
+}
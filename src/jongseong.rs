@@ -1,5 +1,6 @@
 use crate::characters::{JongseongCharacter::*, *};
 use crate::constants::*;
+use crate::error::*;
 use crate::syllable::*;
 use std::convert::TryFrom;
 
@@ -111,7 +112,7 @@ impl JongseongCharacter {
 }
 
 impl TryFrom<u32> for Jongseong {
-    type Error = ();
+    type Error = HangulError;
 
     fn try_from(item: u32) -> Result<Self, Self::Error> {
         let character = match to_code(item) {
@@ -170,7 +171,7 @@ impl TryFrom<u32> for Jongseong {
             0x314C => Jongseong::Compat(Tieut),
             0x314D => Jongseong::Compat(Pieup),
             0x314E => Jongseong::Compat(Hieuh),
-            _ => return Err(()),
+            _ => return Err(HangulError::new(item, HangulCategory::Jongseong)),
         };
 
         Ok(character)
@@ -243,7 +244,7 @@ impl From<Jongseong> for u32 {
 }
 
 impl TryFrom<char> for Jongseong {
-    type Error = ();
+    type Error = HangulError;
 
     fn try_from(item: char) -> Result<Self, Self::Error> {
         Jongseong::try_from(item as u32)
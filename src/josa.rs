@@ -0,0 +1,336 @@
+use crate::characters::*;
+use crate::jongseong::*;
+use crate::syllable::*;
+use std::convert::TryFrom;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Josa {
+    /// 은/는
+    EunNeun,
+    /// 이/가
+    IGa,
+    /// 을/를
+    EulReul,
+    /// 과/와
+    GwaWa,
+    /// 이나/나
+    INa,
+    /// 으로/로
+    EuroRo,
+    /// 아/야
+    AYa,
+    /// 이여/여
+    IyeoYeo,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum FinalSound {
+    Vowel,
+    Rieul,
+    Consonant,
+}
+
+const CLOSING_CHARACTERS: &[char] = &[
+    ')', ']', '}', '>', '"', '\'', '”', '’', '」', '』', '》', '〉',
+];
+
+/// Units are read as their Korean names, e.g. `km` as 킬로미터.
+const UNITS: &[(&str, FinalSound)] = &[
+    ("%", FinalSound::Vowel),
+    ("℃", FinalSound::Vowel),
+    ("°C", FinalSound::Vowel),
+    ("°", FinalSound::Vowel),
+    ("km", FinalSound::Vowel),
+    ("cm", FinalSound::Vowel),
+    ("mm", FinalSound::Vowel),
+    ("m", FinalSound::Vowel),
+    ("kg", FinalSound::Consonant),
+    ("mg", FinalSound::Consonant),
+    ("g", FinalSound::Consonant),
+    ("ml", FinalSound::Vowel),
+    ("mL", FinalSound::Vowel),
+    ("l", FinalSound::Vowel),
+    ("L", FinalSound::Vowel),
+    ("KB", FinalSound::Vowel),
+    ("MB", FinalSound::Vowel),
+    ("GB", FinalSound::Vowel),
+    ("TB", FinalSound::Vowel),
+    ("Hz", FinalSound::Vowel),
+    ("h", FinalSound::Consonant),
+    ("min", FinalSound::Consonant),
+    ("s", FinalSound::Vowel),
+];
+
+pub(crate) fn final_sound(word: &str) -> Option<FinalSound> {
+    let word =
+        word.trim_end_matches(|c: char| c.is_whitespace() || CLOSING_CHARACTERS.contains(&c));
+    let last = word.chars().last()?;
+
+    if last.is_syllable() {
+        syllable_sound(last)
+    } else if let Some(sound) = unit_sound(word) {
+        Some(sound)
+    } else if last.is_ascii_digit() {
+        number_sound(word)
+    } else if last.is_ascii_alphabetic() {
+        latin_sound(word)
+    } else {
+        None
+    }
+}
+
+fn syllable_sound(last: char) -> Option<FinalSound> {
+    let sound = if !last.has_jongseong() {
+        FinalSound::Vowel
+    } else {
+        match Jongseong::try_from(last).map(JongseongCharacter::from) {
+            Ok(JongseongCharacter::Rieul) => FinalSound::Rieul,
+            _ => FinalSound::Consonant,
+        }
+    };
+
+    Some(sound)
+}
+
+fn unit_sound(word: &str) -> Option<FinalSound> {
+    UNITS.iter().find_map(|(unit, sound)| {
+        let number = word.strip_suffix(unit)?.trim_end();
+        if number.ends_with(|c: char| c.is_ascii_digit()) {
+            Some(*sound)
+        } else {
+            None
+        }
+    })
+}
+
+/// Numbers are read in Sino-Korean, e.g. `3` as 삼 and `1000` as 천.
+fn number_sound(word: &str) -> Option<FinalSound> {
+    let start = word
+        .trim_end_matches(|c: char| c.is_ascii_digit() || c == ',')
+        .len();
+    let digits: Vec<u8> = word[start..]
+        .bytes()
+        .filter(u8::is_ascii_digit)
+        .map(|digit| digit - b'0')
+        .collect();
+    let is_decimal = word[..start].ends_with('.');
+
+    let zeros = digits.iter().rev().take_while(|digit| **digit == 0).count();
+    let digit = if is_decimal || zeros == 0 || zeros == digits.len() {
+        *digits.last()?
+    } else {
+        return match (zeros % 4, zeros / 4) {
+            // 십, 백, 천
+            (1..=3, _) => Some(FinalSound::Consonant),
+            // 조
+            (_, 3) => Some(FinalSound::Vowel),
+            // 만, 억, 경
+            _ => Some(FinalSound::Consonant),
+        };
+    };
+
+    let sound = match digit {
+        // 영, 삼, 육
+        0 | 3 | 6 => FinalSound::Consonant,
+        // 일, 칠, 팔
+        1 | 7 | 8 => FinalSound::Rieul,
+        // 이, 사, 오, 구
+        _ => FinalSound::Vowel,
+    };
+
+    Some(sound)
+}
+
+/// Guesses how an English word or acronym is written in Hangul, e.g. `Apple` as 애플.
+fn latin_sound(word: &str) -> Option<FinalSound> {
+    let start = word
+        .trim_end_matches(|c: char| c.is_ascii_alphabetic())
+        .len();
+    let letters = &word[start..];
+    let last = letters.chars().last()?;
+
+    if letters.len() <= 3 && letters.chars().all(|c| c.is_ascii_uppercase()) {
+        let sound = match last {
+            // 엘, 알
+            'L' | 'R' => FinalSound::Rieul,
+            // 엠, 엔
+            'M' | 'N' => FinalSound::Consonant,
+            _ => FinalSound::Vowel,
+        };
+
+        return Some(sound);
+    }
+
+    let letters = letters.to_ascii_lowercase();
+    let is_vowel = |c: char| matches!(c, 'a' | 'e' | 'i' | 'o' | 'u');
+    let mut reversed = letters.chars().rev().skip(1);
+    let previous = reversed.next();
+    let before_previous = reversed.next();
+
+    let sound = if letters.ends_with("ng") || letters.ends_with("me") || letters.ends_with("ne") {
+        FinalSound::Consonant
+    } else if letters.ends_with("le") {
+        FinalSound::Rieul
+    } else {
+        match last.to_ascii_lowercase() {
+            'm' | 'n' => FinalSound::Consonant,
+            'l' => FinalSound::Rieul,
+            'k' if previous == Some('c') || letters.ends_with("ook") => FinalSound::Consonant,
+            'b' | 'c' | 'k' | 'p' | 't'
+                if previous.map_or(false, is_vowel) && !before_previous.map_or(false, is_vowel) =>
+            {
+                FinalSound::Consonant
+            }
+            _ => FinalSound::Vowel,
+        }
+    };
+
+    Some(sound)
+}
+
+impl Josa {
+    /// Returns the form used after a final consonant, and the form used after a vowel.
+    pub fn forms(&self) -> (&'static str, &'static str) {
+        match self {
+            Josa::EunNeun => ("은", "는"),
+            Josa::IGa => ("이", "가"),
+            Josa::EulReul => ("을", "를"),
+            Josa::GwaWa => ("과", "와"),
+            Josa::INa => ("이나", "나"),
+            Josa::EuroRo => ("으로", "로"),
+            Josa::AYa => ("아", "야"),
+            Josa::IyeoYeo => ("이여", "여"),
+        }
+    }
+
+    /// Used when the pronunciation of the word cannot be determined, e.g. `을(를)`.
+    pub fn fallback(&self) -> &'static str {
+        match self {
+            Josa::EunNeun => "은(는)",
+            Josa::IGa => "이(가)",
+            Josa::EulReul => "을(를)",
+            Josa::GwaWa => "과(와)",
+            Josa::INa => "(이)나",
+            Josa::EuroRo => "(으)로",
+            Josa::AYa => "아(야)",
+            Josa::IyeoYeo => "(이)여",
+        }
+    }
+
+    pub fn select(&self, word: &str) -> &'static str {
+        let (consonant, vowel) = self.forms();
+        match final_sound(word) {
+            Some(FinalSound::Vowel) => vowel,
+            Some(FinalSound::Rieul) if *self == Josa::EuroRo => vowel,
+            Some(FinalSound::Rieul) | Some(FinalSound::Consonant) => consonant,
+            None => self.fallback(),
+        }
+    }
+
+    pub fn attach(&self, word: &str) -> String {
+        format!("{}{}", word, self.select(word))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn select_after_consonant() {
+        assert_eq!(Josa::EunNeun.select("사람"), "은");
+        assert_eq!(Josa::IGa.select("책"), "이");
+        assert_eq!(Josa::EulReul.select("밥"), "을");
+        assert_eq!(Josa::GwaWa.select("빵"), "과");
+        assert_eq!(Josa::INa.select("물"), "이나");
+        assert_eq!(Josa::AYa.select("민준"), "아");
+        assert_eq!(Josa::IyeoYeo.select("청춘"), "이여");
+    }
+
+    #[test]
+    fn select_after_vowel() {
+        assert_eq!(Josa::EunNeun.select("나무"), "는");
+        assert_eq!(Josa::IGa.select("사과"), "가");
+        assert_eq!(Josa::EulReul.select("사과"), "를");
+        assert_eq!(Josa::GwaWa.select("우유"), "와");
+        assert_eq!(Josa::INa.select("커피"), "나");
+        assert_eq!(Josa::AYa.select("지수"), "야");
+        assert_eq!(Josa::IyeoYeo.select("사랑하는 그대"), "여");
+    }
+
+    #[test]
+    fn select_euro_ro() {
+        assert_eq!(Josa::EuroRo.select("집"), "으로");
+        assert_eq!(Josa::EuroRo.select("학교"), "로");
+        assert_eq!(Josa::EuroRo.select("서울"), "로");
+        assert_eq!(Josa::EuroRo.select("연필"), "로");
+        assert_eq!(Josa::EulReul.select("서울"), "을");
+    }
+
+    #[test]
+    fn select_fallback() {
+        assert_eq!(Josa::EulReul.select(""), "을(를)");
+        assert_eq!(Josa::EuroRo.select("ㄱ"), "(으)로");
+        assert_eq!(Josa::IGa.select("!?"), "이(가)");
+    }
+
+    #[test]
+    fn select_after_number() {
+        assert_eq!(Josa::EulReul.select("3"), "을");
+        assert_eq!(Josa::EulReul.select("2"), "를");
+        assert_eq!(Josa::EuroRo.select("1"), "로");
+        assert_eq!(Josa::EuroRo.select("6"), "으로");
+        assert_eq!(Josa::IGa.select("10"), "이");
+        assert_eq!(Josa::IGa.select("2,000"), "이");
+        assert_eq!(Josa::IGa.select("1000000000000"), "가");
+        assert_eq!(Josa::IGa.select("0"), "이");
+        assert_eq!(Josa::EunNeun.select("1.5"), "는");
+        assert_eq!(Josa::EunNeun.select("버전 2.10"), "은");
+        assert_eq!(Josa::EunNeun.select("Windows 11"), "은");
+    }
+
+    #[test]
+    fn select_after_unit() {
+        assert_eq!(Josa::IGa.select("50%"), "가");
+        assert_eq!(Josa::EulReul.select("5 km"), "를");
+        assert_eq!(Josa::EulReul.select("3kg"), "을");
+        assert_eq!(Josa::EuroRo.select("36.5℃"), "로");
+        assert_eq!(Josa::EunNeun.select("128GB"), "는");
+    }
+
+    #[test]
+    fn select_after_latin() {
+        assert_eq!(Josa::EulReul.select("Apple"), "을");
+        assert_eq!(Josa::EuroRo.select("Apple"), "로");
+        assert_eq!(Josa::EulReul.select("iPhone"), "을");
+        assert_eq!(Josa::EunNeun.select("Amazon"), "은");
+        assert_eq!(Josa::EunNeun.select("Samsung"), "은");
+        assert_eq!(Josa::EunNeun.select("Linux"), "는");
+        assert_eq!(Josa::EunNeun.select("computer"), "는");
+        assert_eq!(Josa::EunNeun.select("Facebook"), "은");
+        assert_eq!(Josa::EunNeun.select("chat"), "은");
+        assert_eq!(Josa::EunNeun.select("test"), "는");
+        assert_eq!(Josa::EunNeun.select("Excel"), "은");
+    }
+
+    #[test]
+    fn select_after_acronym() {
+        assert_eq!(Josa::IGa.select("IBM"), "이");
+        assert_eq!(Josa::IGa.select("USB"), "가");
+        assert_eq!(Josa::EuroRo.select("SQL"), "로");
+        assert_eq!(Josa::EuroRo.select("LTE"), "로");
+    }
+
+    #[test]
+    fn select_skips_closing_characters() {
+        assert_eq!(Josa::EulReul.select("\"사과\""), "를");
+        assert_eq!(Josa::EunNeun.select("삼성(Samsung)"), "은");
+    }
+
+    #[test]
+    fn attach() {
+        assert_eq!(Josa::EulReul.attach("사과"), "사과를");
+        assert_eq!(Josa::EunNeun.attach("한글"), "한글은");
+        assert_eq!(Josa::IGa.attach("v2"), "v2가");
+    }
+}
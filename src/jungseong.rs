@@ -1,5 +1,6 @@
 use crate::characters::{JungseongCharacter::*, *};
 use crate::constants::*;
+use crate::error::*;
 use crate::syllable::*;
 use std::convert::TryFrom;
 
@@ -11,10 +12,8 @@ pub enum Jungseong {
 
 fn to_code(code: u32) -> u32 {
     if code.is_syllable() {
-        let jongseong_code = (code - HANGEUL_OFFSET) % JUNGSEONG_COUNT;
-        let value = ((code - HANGEUL_OFFSET - jongseong_code)
-            % (JUNGSEONG_COUNT * JONGSEONG_COUNT))
-            / JUNGSEONG_COUNT;
+        let value =
+            ((code - HANGEUL_OFFSET) % (JUNGSEONG_COUNT * JONGSEONG_COUNT)) / JONGSEONG_COUNT;
         value + JUNGSEONG_START
     } else {
         code
@@ -114,7 +113,7 @@ impl JungseongCharacter {
 }
 
 impl TryFrom<u32> for Jungseong {
-    type Error = ();
+    type Error = HangulError;
 
     fn try_from(item: u32) -> Result<Self, Self::Error> {
         let character = match to_code(item) {
@@ -161,7 +160,7 @@ impl TryFrom<u32> for Jungseong {
             0x3161 => Jungseong::Compat(EU),
             0x3162 => Jungseong::Compat(YI),
             0x3163 => Jungseong::Compat(I),
-            _ => return Err(()),
+            _ => return Err(HangulError::new(item, HangulCategory::Jungseong)),
         };
 
         Ok(character)
@@ -222,7 +221,7 @@ impl From<Jungseong> for u32 {
 }
 
 impl TryFrom<char> for Jungseong {
-    type Error = ();
+    type Error = HangulError;
 
     fn try_from(item: char) -> Result<Self, Self::Error> {
         Jungseong::try_from(item as u32)
@@ -425,4 +424,14 @@ mod tests {
         }
         assert_eq!(NON_COMPAT_JUNGSEONG_END_CHAR.is_compat_jungseong(), false);
     }
+
+    #[test]
+    fn jungseong_of_syllables() {
+        assert_eq!(Jungseong::try_from('가'), Ok(Jungseong::Normal(A)));
+        assert_eq!(Jungseong::try_from('각'), Ok(Jungseong::Normal(A)));
+        assert_eq!(Jungseong::try_from('개'), Ok(Jungseong::Normal(AE)));
+        assert_eq!(Jungseong::try_from('괘'), Ok(Jungseong::Normal(WAE)));
+        assert_eq!(Jungseong::try_from('의'), Ok(Jungseong::Normal(YI)));
+        assert_eq!(Jungseong::try_from('힣'), Ok(Jungseong::Normal(I)));
+    }
 }
@@ -1,12 +1,42 @@
+#![cfg_attr(test, allow(clippy::bool_assert_comparison))]
+
 pub mod characters;
 pub mod choseong;
+pub mod compose;
 pub mod constants;
+pub mod conjugation;
+pub mod datetime;
+pub mod decompose;
+pub mod error;
+pub mod ime;
+pub mod ipa;
+pub mod jamo;
 pub mod jongseong;
+pub mod josa;
 pub mod jungseong;
+pub mod normalize;
+pub mod number;
+pub mod pronunciation;
+pub mod romanization;
+pub mod search;
 pub mod syllable;
 
 pub use characters::*;
 pub use choseong::*;
+pub use compose::*;
+pub use conjugation::*;
+pub use datetime::*;
+pub use decompose::*;
+pub use error::*;
+pub use ime::*;
+pub use ipa::*;
+pub use jamo::*;
 pub use jongseong::*;
+pub use josa::*;
 pub use jungseong::*;
+pub use normalize::*;
+pub use number::*;
+pub use pronunciation::*;
+pub use romanization::*;
+pub use search::*;
 pub use syllable::*;
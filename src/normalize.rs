@@ -0,0 +1,128 @@
+use crate::constants::*;
+use std::char;
+
+/// The conjoining jongseong base, one before the first jongseong (U+11A7).
+const JONGSEONG_BASE: u32 = JONGSEONG_START - 1;
+/// The number of syllables that share a choseong.
+const SYLLABLES_PER_CHOSEONG: u32 = JUNGSEONG_COUNT * JONGSEONG_COUNT;
+
+fn is_choseong(code: u32) -> bool {
+    (CHOSEONG_START..CHOSEONG_START + CHOSEONG_COUNT).contains(&code)
+}
+
+fn is_jungseong(code: u32) -> bool {
+    (JUNGSEONG_START..JUNGSEONG_START + JUNGSEONG_COUNT).contains(&code)
+}
+
+fn is_jongseong(code: u32) -> bool {
+    (JONGSEONG_START..JONGSEONG_BASE + JONGSEONG_COUNT).contains(&code)
+}
+
+fn is_syllable(code: u32) -> bool {
+    (SYLLABLE_START..=SYLLABLE_END).contains(&code)
+}
+
+fn from_code(code: u32) -> char {
+    char::from_u32(code).expect("hangul code point")
+}
+
+/// Decomposes precomposed syllables into conjoining jamo (NFD), e.g. 한 → U+1112 U+1161 U+11AB.
+/// Everything else is kept as is.
+///
+/// See:
+/// * https://www.unicode.org/versions/latest/ch03.pdf (3.12 Conjoining Jamo Behavior)
+pub fn to_nfd(text: &str) -> String {
+    let mut normalized = String::with_capacity(text.len() * 3);
+    for character in text.chars() {
+        let code = character as u32;
+        if !is_syllable(code) {
+            normalized.push(character);
+            continue;
+        }
+
+        let index = code - SYLLABLE_START;
+        normalized.push(from_code(CHOSEONG_START + index / SYLLABLES_PER_CHOSEONG));
+        normalized.push(from_code(
+            JUNGSEONG_START + index % SYLLABLES_PER_CHOSEONG / JONGSEONG_COUNT,
+        ));
+        if index % JONGSEONG_COUNT != 0 {
+            normalized.push(from_code(JONGSEONG_BASE + index % JONGSEONG_COUNT));
+        }
+    }
+    normalized
+}
+
+/// Composes conjoining jamo into precomposed syllables (NFC), e.g. U+1112 U+1161 U+11AB → 한.
+/// A jongseong also joins a precomposed syllable without one, and jamo that form no syllable are kept.
+///
+/// See:
+/// * https://www.unicode.org/versions/latest/ch03.pdf (3.12 Conjoining Jamo Behavior)
+pub fn to_nfc(text: &str) -> String {
+    let mut normalized = String::with_capacity(text.len());
+    let mut last: Option<u32> = None;
+
+    for character in text.chars() {
+        let code = character as u32;
+        let composed = match last {
+            Some(choseong) if is_choseong(choseong) && is_jungseong(code) => Some(
+                SYLLABLE_START
+                    + ((choseong - CHOSEONG_START) * JUNGSEONG_COUNT + (code - JUNGSEONG_START))
+                        * JONGSEONG_COUNT,
+            ),
+            Some(syllable)
+                if is_syllable(syllable)
+                    && (syllable - SYLLABLE_START) % JONGSEONG_COUNT == 0
+                    && is_jongseong(code) =>
+            {
+                Some(syllable + (code - JONGSEONG_BASE))
+            }
+            _ => None,
+        };
+
+        match composed {
+            Some(composed) => {
+                normalized.pop();
+                normalized.push(from_code(composed));
+                last = Some(composed);
+            }
+            None => {
+                normalized.push(character);
+                last = Some(code);
+            }
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decomposition() {
+        assert_eq!(to_nfd("한"), "\u{1112}\u{1161}\u{11AB}");
+        assert_eq!(to_nfd("가"), "\u{1100}\u{1161}");
+        assert_eq!(to_nfd("힣"), "\u{1112}\u{1175}\u{11C2}");
+        assert_eq!(to_nfd("a한b"), "a\u{1112}\u{1161}\u{11AB}b");
+        assert_eq!(to_nfd("ㅎㅏㄴ"), "ㅎㅏㄴ");
+    }
+
+    #[test]
+    fn composition() {
+        assert_eq!(to_nfc("\u{1112}\u{1161}\u{11AB}"), "한");
+        assert_eq!(to_nfc("\u{1100}\u{1161}"), "가");
+        assert_eq!(to_nfc("가\u{11A8}"), "각");
+        assert_eq!(to_nfc("각\u{11A8}"), "각\u{11A8}");
+        assert_eq!(to_nfc("\u{1100}\u{1100}\u{1161}"), "\u{1100}가");
+        assert_eq!(to_nfc("\u{1161}\u{11A8}"), "\u{1161}\u{11A8}");
+        assert_eq!(to_nfc("ㅎㅏㄴ"), "ㅎㅏㄴ");
+    }
+
+    #[test]
+    fn round_trip() {
+        let text = "다람쥐 헌 쳇바퀴에 타고파, 뛩!";
+        assert_eq!(to_nfc(&to_nfd(text)), text);
+        assert_eq!(to_nfd(&to_nfc(&to_nfd(text))), to_nfd(text));
+        assert_eq!(to_nfc("한글.txt"), to_nfc(&to_nfd("한글.txt")));
+    }
+}
@@ -0,0 +1,538 @@
+use crate::error::*;
+use std::convert::TryFrom;
+
+pub(crate) const SINO_DIGITS: [&str; 10] =
+    ["", "일", "이", "삼", "사", "오", "육", "칠", "팔", "구"];
+
+/// Units within a group of four digits.
+pub(crate) const SINO_UNITS: [&str; 4] = ["", "십", "백", "천"];
+
+/// Units of each group of four digits, grouped by 만 (10^4).
+pub(crate) const GROUP_UNITS: [&str; 5] = ["", "만", "억", "조", "경"];
+
+pub(crate) const NATIVE_DIGITS: [&str; 10] = [
+    "", "하나", "둘", "셋", "넷", "다섯", "여섯", "일곱", "여덟", "아홉",
+];
+
+pub(crate) const NATIVE_TENS: [&str; 10] = [
+    "", "열", "스물", "서른", "마흔", "쉰", "예순", "일흔", "여든", "아흔",
+];
+
+/// Spells a group of up to four digits, leaving out 일 before 십, 백 and 천.
+fn sino_group(group: u64) -> String {
+    let mut spelled = String::new();
+
+    for position in (0..4).rev() {
+        let digit = (group / 10u64.pow(position)) % 10;
+        if digit == 0 {
+            continue;
+        }
+        if digit != 1 || position == 0 {
+            spelled.push_str(SINO_DIGITS[digit as usize]);
+        }
+        spelled.push_str(SINO_UNITS[position as usize]);
+    }
+
+    spelled
+}
+
+/// Spells a number with Sino-Korean numerals, spaced by 만 (10^4),
+/// e.g. 320000500 → 삼억 이천만 오백.
+pub fn sino_korean(number: u64) -> String {
+    if number == 0 {
+        return "영".to_string();
+    }
+
+    let mut groups = vec![];
+    let mut rest = number;
+    for unit in GROUP_UNITS.iter() {
+        let group = rest % 10000;
+        rest /= 10000;
+        if group == 0 {
+            continue;
+        }
+        // 10000 is 만 rather than 일만, but 억 and above keep 일.
+        let spelled = match (group, *unit) {
+            (1, "만") => String::new(),
+            _ => sino_group(group),
+        };
+        groups.push(format!("{}{}", spelled, unit));
+    }
+
+    groups.reverse();
+    groups.join(" ")
+}
+
+fn native(number: u64, attributive: bool) -> String {
+    let tens = (number % 100 / 10) as usize;
+    let ones = (number % 10) as usize;
+    let mut spelled = String::new();
+
+    if number >= 100 {
+        spelled.push_str(&sino_korean(number / 100 * 100));
+        if number % 100 != 0 && number % 10000 < 100 {
+            spelled.push(' ');
+        }
+    }
+
+    match (tens, ones) {
+        (2, 0) if attributive => spelled.push_str("스무"),
+        _ => spelled.push_str(NATIVE_TENS[tens]),
+    }
+    match ones {
+        1 if attributive => spelled.push('한'),
+        2 if attributive => spelled.push('두'),
+        3 if attributive => spelled.push('세'),
+        4 if attributive => spelled.push('네'),
+        _ => spelled.push_str(NATIVE_DIGITS[ones]),
+    }
+
+    spelled
+}
+
+/// Spells a number with native Korean numerals, e.g. 25 → 스물다섯.
+///
+/// Native numerals only go up to 99, so hundreds and above are Sino-Korean, e.g. 123 → 백스물셋.
+/// 0 has no native numeral and is spelled 영.
+pub fn native_korean(number: u64) -> String {
+    if number == 0 {
+        return "영".to_string();
+    }
+    native(number, false)
+}
+
+/// Spells a number with the native Korean numerals used before a counter,
+/// e.g. 1 → 한, 20 → 스무, 24 → 스물네.
+pub fn native_korean_attributive(number: u64) -> String {
+    if number == 0 {
+        return "영".to_string();
+    }
+    native(number, true)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NumberSystem {
+    /// 일, 이, 삼
+    SinoKorean,
+    /// 한, 두, 세
+    NativeKorean,
+}
+
+/// Counters counted with native numerals. Others are counted with Sino-Korean numerals.
+const NATIVE_COUNTERS: &[&str] = &[
+    "개", "명", "사람", "마리", "권", "시", "시간", "살", "잔", "병", "대", "장", "번", "번째",
+    "벌", "켤레", "그루", "송이", "채", "척", "통", "달", "가지", "군데", "자루", "봉지", "그릇",
+    "상자", "곳", "줄",
+];
+
+/// The numerals used with a counter (분류사), e.g. native for 개 and Sino-Korean for 분.
+pub fn counter_system(counter: &str) -> NumberSystem {
+    if NATIVE_COUNTERS.contains(&counter) {
+        NumberSystem::NativeKorean
+    } else {
+        NumberSystem::SinoKorean
+    }
+}
+
+/// Spells a quantity with its counter, e.g. `count(3, "개")` → 세 개 and `count(3, "분")` → 삼 분.
+///
+/// The first of an ordinal counted with 번째 is 첫, e.g. 첫 번째.
+pub fn count(number: u64, counter: &str) -> String {
+    let spelled = match counter_system(counter) {
+        NumberSystem::NativeKorean if number == 1 && counter == "번째" => "첫".to_string(),
+        NumberSystem::NativeKorean => native_korean_attributive(number),
+        NumberSystem::SinoKorean => sino_korean(number),
+    };
+
+    format!("{} {}", spelled, counter)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Word {
+    Digit(i128),
+    Unit(i128),
+    GroupUnit(i128),
+    NativeTens(i128),
+}
+
+const WORDS: &[(&str, Word)] = &[
+    ("영", Word::Digit(0)),
+    ("공", Word::Digit(0)),
+    ("일", Word::Digit(1)),
+    ("이", Word::Digit(2)),
+    ("삼", Word::Digit(3)),
+    ("사", Word::Digit(4)),
+    ("오", Word::Digit(5)),
+    ("육", Word::Digit(6)),
+    ("칠", Word::Digit(7)),
+    ("팔", Word::Digit(8)),
+    ("구", Word::Digit(9)),
+    ("십", Word::Unit(10)),
+    ("백", Word::Unit(100)),
+    ("천", Word::Unit(1000)),
+    ("만", Word::GroupUnit(10_000)),
+    ("억", Word::GroupUnit(100_000_000)),
+    ("조", Word::GroupUnit(1_000_000_000_000)),
+    ("경", Word::GroupUnit(10_000_000_000_000_000)),
+    ("하나", Word::Digit(1)),
+    ("한", Word::Digit(1)),
+    ("둘", Word::Digit(2)),
+    ("두", Word::Digit(2)),
+    ("셋", Word::Digit(3)),
+    ("세", Word::Digit(3)),
+    ("넷", Word::Digit(4)),
+    ("네", Word::Digit(4)),
+    ("다섯", Word::Digit(5)),
+    ("여섯", Word::Digit(6)),
+    ("일곱", Word::Digit(7)),
+    ("여덟", Word::Digit(8)),
+    ("아홉", Word::Digit(9)),
+    ("열", Word::NativeTens(10)),
+    ("스물", Word::NativeTens(20)),
+    ("스무", Word::NativeTens(20)),
+    ("서른", Word::NativeTens(30)),
+    ("마흔", Word::NativeTens(40)),
+    ("쉰", Word::NativeTens(50)),
+    ("예순", Word::NativeTens(60)),
+    ("일흔", Word::NativeTens(70)),
+    ("여든", Word::NativeTens(80)),
+    ("아흔", Word::NativeTens(90)),
+];
+
+/// The longest word at the start of `text`.
+fn match_word(text: &str) -> Option<(&'static str, Word)> {
+    WORDS
+        .iter()
+        .filter(|(word, _)| text.starts_with(word))
+        .max_by_key(|(word, _)| word.len())
+        .cloned()
+}
+
+#[derive(Debug, Default)]
+struct NumberParser {
+    total: i128,
+    group: i128,
+    digit: Option<i128>,
+    unit: Option<i128>,
+    group_unit: Option<i128>,
+    tens: bool,
+}
+
+impl NumberParser {
+    /// Adds a word found at `position`, spelled as `spelling`.
+    fn push(&mut self, word: Word, position: usize, spelling: &str) -> Result<(), NumberError> {
+        let misplaced = || NumberError::Misplaced {
+            position,
+            word: spelling.to_string(),
+        };
+
+        match word {
+            Word::Digit(value) => {
+                if self.digit.is_some() {
+                    return Err(misplaced());
+                }
+                self.digit = Some(value);
+            }
+            Word::Unit(unit) => {
+                if self.tens || self.unit.map_or(false, |last| unit >= last) {
+                    return Err(misplaced());
+                }
+                let value = self.digit.take().unwrap_or(1).checked_mul(unit);
+                self.group = value
+                    .and_then(|value| self.group.checked_add(value))
+                    .ok_or(NumberError::Overflow)?;
+                self.unit = Some(unit);
+            }
+            Word::GroupUnit(unit) => {
+                if self.group_unit.map_or(false, |last| unit >= last) {
+                    return Err(misplaced());
+                }
+                let group = match (self.group, self.digit.take()) {
+                    (0, None) => 1,
+                    (group, digit) => group
+                        .checked_add(digit.unwrap_or(0))
+                        .ok_or(NumberError::Overflow)?,
+                };
+                self.total = group
+                    .checked_mul(unit)
+                    .and_then(|value| self.total.checked_add(value))
+                    .ok_or(NumberError::Overflow)?;
+                self.group = 0;
+                self.unit = None;
+                self.group_unit = Some(unit);
+                self.tens = false;
+            }
+            Word::NativeTens(value) => {
+                if self.tens || self.digit.is_some() {
+                    return Err(misplaced());
+                }
+                self.group = self.group.checked_add(value).ok_or(NumberError::Overflow)?;
+                self.tens = true;
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> Option<i128> {
+        self.total
+            .checked_add(self.group)?
+            .checked_add(self.digit.unwrap_or(0))
+    }
+}
+
+fn parse(text: &str, signed: bool) -> Result<i128, NumberError> {
+    let mut parser = NumberParser::default();
+    let mut negative = false;
+    let mut empty = true;
+    let mut rest = text.trim_start();
+    let mut position = text.chars().count() - rest.chars().count();
+
+    if signed {
+        for sign in &["-", "마이너스"] {
+            if let Some(after) = rest.strip_prefix(sign) {
+                negative = true;
+                position += sign.chars().count();
+                rest = after;
+                break;
+            }
+        }
+    }
+
+    while let Some(character) = rest.chars().next() {
+        if character.is_whitespace() || character == ',' {
+            position += 1;
+            rest = &rest[character.len_utf8()..];
+            continue;
+        }
+
+        let (length, word, spelling) = if character.is_ascii_digit() {
+            let digits: String = rest
+                .chars()
+                .take_while(|character| character.is_ascii_digit() || *character == ',')
+                .collect();
+            let digits = digits.trim_end_matches(',');
+            let value = digits
+                .chars()
+                .filter(char::is_ascii_digit)
+                .try_fold(0i128, |value, digit| {
+                    value
+                        .checked_mul(10)?
+                        .checked_add(digit.to_digit(10)? as i128)
+                })
+                .ok_or(NumberError::Overflow)?;
+            (digits.len(), Word::Digit(value), digits.to_string())
+        } else {
+            match match_word(rest) {
+                Some((word, value)) => (word.len(), value, word.to_string()),
+                None => {
+                    return Err(NumberError::UnexpectedCharacter {
+                        position,
+                        character,
+                    })
+                }
+            }
+        };
+
+        parser.push(word, position, &spelling)?;
+        empty = false;
+        position += rest[..length].chars().count();
+        rest = &rest[length..];
+    }
+
+    if empty {
+        return Err(NumberError::Empty);
+    }
+    let value = parser.finish().ok_or(NumberError::Overflow)?;
+    Ok(if negative { -value } else { value })
+}
+
+/// Reads a number written in Korean, with digits, or both,
+/// e.g. 삼억 이천만 오백, 3억 2천만 or 스물다섯.
+pub fn parse_number(text: &str) -> Result<u64, NumberError> {
+    let value = parse(text, false)?;
+    u64::try_from(value).map_err(|_| NumberError::Overflow)
+}
+
+/// Reads a number like [`parse_number`], which may start with `-` or 마이너스.
+pub fn parse_signed_number(text: &str) -> Result<i128, NumberError> {
+    parse(text, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sino() {
+        assert_eq!(sino_korean(0), "영");
+        assert_eq!(sino_korean(1), "일");
+        assert_eq!(sino_korean(10), "십");
+        assert_eq!(sino_korean(11), "십일");
+        assert_eq!(sino_korean(16), "십육");
+        assert_eq!(sino_korean(110), "백십");
+        assert_eq!(sino_korean(1999), "천구백구십구");
+        assert_eq!(sino_korean(2026), "이천이십육");
+    }
+
+    #[test]
+    fn sino_groups() {
+        assert_eq!(sino_korean(10000), "만");
+        assert_eq!(sino_korean(12345), "만 이천삼백사십오");
+        assert_eq!(sino_korean(110000), "십일만");
+        assert_eq!(sino_korean(320000500), "삼억 이천만 오백");
+        assert_eq!(sino_korean(100000000), "일억");
+        assert_eq!(sino_korean(100010000), "일억 만");
+        assert_eq!(sino_korean(1000000000000), "일조");
+        assert_eq!(
+            sino_korean(u64::MAX),
+            "천팔백사십사경 육천칠백사십사조 칠백삼십칠억 구백오십오만 천육백십오"
+        );
+    }
+
+    #[test]
+    fn native() {
+        assert_eq!(native_korean(1), "하나");
+        assert_eq!(native_korean(10), "열");
+        assert_eq!(native_korean(20), "스물");
+        assert_eq!(native_korean(25), "스물다섯");
+        assert_eq!(native_korean(99), "아흔아홉");
+        assert_eq!(native_korean(100), "백");
+        assert_eq!(native_korean(123), "백스물셋");
+        assert_eq!(native_korean(10001), "만 하나");
+    }
+
+    #[test]
+    fn native_attributive() {
+        assert_eq!(native_korean_attributive(1), "한");
+        assert_eq!(native_korean_attributive(2), "두");
+        assert_eq!(native_korean_attributive(3), "세");
+        assert_eq!(native_korean_attributive(4), "네");
+        assert_eq!(native_korean_attributive(5), "다섯");
+        assert_eq!(native_korean_attributive(11), "열한");
+        assert_eq!(native_korean_attributive(20), "스무");
+        assert_eq!(native_korean_attributive(21), "스물한");
+        assert_eq!(native_korean_attributive(120), "백스무");
+    }
+
+    #[test]
+    fn parse_sino() {
+        assert_eq!(parse_number("삼억 이천만 오백"), Ok(320000500));
+        assert_eq!(parse_number("삼억이천만오백"), Ok(320000500));
+        assert_eq!(parse_number("만"), Ok(10000));
+        assert_eq!(parse_number("십일만"), Ok(110000));
+        assert_eq!(parse_number("천구백구십구"), Ok(1999));
+        assert_eq!(parse_number("영"), Ok(0));
+        assert_eq!(parse_number(&sino_korean(u64::MAX)), Ok(u64::MAX));
+    }
+
+    #[test]
+    fn parse_digits() {
+        assert_eq!(parse_number("3억 2천만"), Ok(320000000));
+        assert_eq!(parse_number("1,500만"), Ok(15000000));
+        assert_eq!(parse_number("12,345"), Ok(12345));
+        assert_eq!(parse_number("2억 5000"), Ok(200005000));
+    }
+
+    #[test]
+    fn parse_native() {
+        assert_eq!(parse_number("스물다섯"), Ok(25));
+        assert_eq!(parse_number("열"), Ok(10));
+        assert_eq!(parse_number("아흔아홉"), Ok(99));
+        assert_eq!(parse_number("백스물셋"), Ok(123));
+        assert_eq!(parse_number("스무"), Ok(20));
+        assert_eq!(parse_number("일곱"), Ok(7));
+    }
+
+    #[test]
+    fn parse_signed() {
+        assert_eq!(parse_signed_number("-3만"), Ok(-30000));
+        assert_eq!(parse_signed_number("마이너스 오"), Ok(-5));
+        assert_eq!(
+            parse_signed_number("이천경"),
+            Ok(20_000_000_000_000_000_000)
+        );
+        assert_eq!(parse_number("이천경"), Err(NumberError::Overflow));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(parse_number(""), Err(NumberError::Empty));
+        assert_eq!(parse_number("  "), Err(NumberError::Empty));
+        assert_eq!(
+            parse_number("삼만 원"),
+            Err(NumberError::UnexpectedCharacter {
+                position: 3,
+                character: '원',
+            })
+        );
+        assert_eq!(
+            parse_number("-3"),
+            Err(NumberError::UnexpectedCharacter {
+                position: 0,
+                character: '-',
+            })
+        );
+        assert_eq!(
+            parse_number("삼사"),
+            Err(NumberError::Misplaced {
+                position: 1,
+                word: "사".to_string(),
+            })
+        );
+        assert_eq!(
+            parse_number("백천"),
+            Err(NumberError::Misplaced {
+                position: 1,
+                word: "천".to_string(),
+            })
+        );
+        assert_eq!(
+            parse_number("만억"),
+            Err(NumberError::Misplaced {
+                position: 1,
+                word: "억".to_string(),
+            })
+        );
+        assert_eq!(
+            parse_number("99999999999999999999999999999999999999999"),
+            Err(NumberError::Overflow)
+        );
+        assert_eq!(
+            parse_number("170141183460469231731687303715884105727만"),
+            Err(NumberError::Overflow)
+        );
+        assert_eq!(
+            parse_signed_number("17014118346046923173168730371588410572십아흔"),
+            Err(NumberError::Overflow)
+        );
+    }
+
+    #[test]
+    fn counters() {
+        assert_eq!(count(3, "개"), "세 개");
+        assert_eq!(count(3, "분"), "삼 분");
+        assert_eq!(count(1, "명"), "한 명");
+        assert_eq!(count(20, "살"), "스무 살");
+        assert_eq!(count(24, "마리"), "스물네 마리");
+        assert_eq!(count(2, "권"), "두 권");
+        assert_eq!(count(15, "층"), "십오 층");
+        assert_eq!(count(10000, "원"), "만 원");
+        assert_eq!(count(3, "시"), "세 시");
+        assert_eq!(count(3, "개월"), "삼 개월");
+    }
+
+    #[test]
+    fn ordinals() {
+        assert_eq!(count(1, "번째"), "첫 번째");
+        assert_eq!(count(2, "번째"), "두 번째");
+        assert_eq!(count(11, "번째"), "열한 번째");
+        assert_eq!(count(3, "번"), "세 번");
+    }
+
+    #[test]
+    fn systems() {
+        assert_eq!(counter_system("개"), NumberSystem::NativeKorean);
+        assert_eq!(counter_system("원"), NumberSystem::SinoKorean);
+        assert_eq!(counter_system("미터"), NumberSystem::SinoKorean);
+    }
+}
@@ -0,0 +1,408 @@
+use crate::characters::*;
+use crate::syllable::*;
+use std::convert::TryFrom;
+
+/// Reduces a final consonant to one of the seven sounds it can be pronounced as.
+pub(crate) fn neutralize(character: &JongseongCharacter) -> JongseongCharacter {
+    match character {
+        JongseongCharacter::Giyeok
+        | JongseongCharacter::SsangGiyeok
+        | JongseongCharacter::GiyeokSiot
+        | JongseongCharacter::RieulGiyeok
+        | JongseongCharacter::Kieuk => JongseongCharacter::Giyeok,
+        JongseongCharacter::Nieun
+        | JongseongCharacter::NieunJieut
+        | JongseongCharacter::NieunHieuh => JongseongCharacter::Nieun,
+        JongseongCharacter::Digeut
+        | JongseongCharacter::Siot
+        | JongseongCharacter::SsangSiot
+        | JongseongCharacter::Jieut
+        | JongseongCharacter::Chieut
+        | JongseongCharacter::Tieut
+        | JongseongCharacter::Hieuh => JongseongCharacter::Digeut,
+        JongseongCharacter::Rieul
+        | JongseongCharacter::RieulBieup
+        | JongseongCharacter::RieulSiot
+        | JongseongCharacter::RieulTieut
+        | JongseongCharacter::RieulHieuh => JongseongCharacter::Rieul,
+        JongseongCharacter::Mieum | JongseongCharacter::RieulMieum => JongseongCharacter::Mieum,
+        JongseongCharacter::Bieup
+        | JongseongCharacter::BieupSiot
+        | JongseongCharacter::Pieup
+        | JongseongCharacter::RieulPieup => JongseongCharacter::Bieup,
+        JongseongCharacter::Ieung => JongseongCharacter::Ieung,
+    }
+}
+
+/// Splits a final consonant that moves over to a following silent ㅇ.
+fn split(character: &JongseongCharacter) -> (Option<JongseongCharacter>, ChoseongCharacter) {
+    match character {
+        JongseongCharacter::Giyeok => (None, ChoseongCharacter::Giyeok),
+        JongseongCharacter::SsangGiyeok => (None, ChoseongCharacter::SsangGiyeok),
+        JongseongCharacter::GiyeokSiot => {
+            (Some(JongseongCharacter::Giyeok), ChoseongCharacter::Siot)
+        }
+        JongseongCharacter::Nieun => (None, ChoseongCharacter::Nieun),
+        JongseongCharacter::NieunJieut => {
+            (Some(JongseongCharacter::Nieun), ChoseongCharacter::Jieut)
+        }
+        JongseongCharacter::NieunHieuh => (None, ChoseongCharacter::Nieun),
+        JongseongCharacter::Digeut => (None, ChoseongCharacter::Digeut),
+        JongseongCharacter::Rieul => (None, ChoseongCharacter::Rieul),
+        JongseongCharacter::RieulGiyeok => {
+            (Some(JongseongCharacter::Rieul), ChoseongCharacter::Giyeok)
+        }
+        JongseongCharacter::RieulMieum => {
+            (Some(JongseongCharacter::Rieul), ChoseongCharacter::Mieum)
+        }
+        JongseongCharacter::RieulBieup => {
+            (Some(JongseongCharacter::Rieul), ChoseongCharacter::Bieup)
+        }
+        JongseongCharacter::RieulSiot => (Some(JongseongCharacter::Rieul), ChoseongCharacter::Siot),
+        JongseongCharacter::RieulTieut => {
+            (Some(JongseongCharacter::Rieul), ChoseongCharacter::Tieut)
+        }
+        JongseongCharacter::RieulPieup => {
+            (Some(JongseongCharacter::Rieul), ChoseongCharacter::Pieup)
+        }
+        JongseongCharacter::RieulHieuh => (None, ChoseongCharacter::Rieul),
+        JongseongCharacter::Mieum => (None, ChoseongCharacter::Mieum),
+        JongseongCharacter::Bieup => (None, ChoseongCharacter::Bieup),
+        JongseongCharacter::BieupSiot => (Some(JongseongCharacter::Bieup), ChoseongCharacter::Siot),
+        JongseongCharacter::Siot => (None, ChoseongCharacter::Siot),
+        JongseongCharacter::SsangSiot => (None, ChoseongCharacter::SsangSiot),
+        JongseongCharacter::Ieung => (Some(JongseongCharacter::Ieung), ChoseongCharacter::Ieung),
+        JongseongCharacter::Jieut => (None, ChoseongCharacter::Jieut),
+        JongseongCharacter::Chieut => (None, ChoseongCharacter::Chieut),
+        JongseongCharacter::Kieuk => (None, ChoseongCharacter::Kiyeok),
+        JongseongCharacter::Tieut => (None, ChoseongCharacter::Tieut),
+        JongseongCharacter::Pieup => (None, ChoseongCharacter::Pieup),
+        JongseongCharacter::Hieuh => (None, ChoseongCharacter::Ieung),
+    }
+}
+
+/// Merges a final consonant with a following ㅎ, e.g. 잡혀 → 자펴.
+fn aspirate_after(
+    character: &JongseongCharacter,
+) -> Option<(Option<JongseongCharacter>, ChoseongCharacter)> {
+    let aspirated = match character {
+        JongseongCharacter::Giyeok
+        | JongseongCharacter::SsangGiyeok
+        | JongseongCharacter::Kieuk => (None, ChoseongCharacter::Kiyeok),
+        JongseongCharacter::RieulGiyeok => {
+            (Some(JongseongCharacter::Rieul), ChoseongCharacter::Kiyeok)
+        }
+        JongseongCharacter::Digeut
+        | JongseongCharacter::Siot
+        | JongseongCharacter::Chieut
+        | JongseongCharacter::Tieut => (None, ChoseongCharacter::Tieut),
+        JongseongCharacter::Jieut => (None, ChoseongCharacter::Chieut),
+        JongseongCharacter::NieunJieut => {
+            (Some(JongseongCharacter::Nieun), ChoseongCharacter::Chieut)
+        }
+        JongseongCharacter::Bieup | JongseongCharacter::Pieup => (None, ChoseongCharacter::Pieup),
+        JongseongCharacter::RieulBieup => {
+            (Some(JongseongCharacter::Rieul), ChoseongCharacter::Pieup)
+        }
+        _ => return None,
+    };
+
+    Some(aspirated)
+}
+
+/// Merges a final ㅎ with a following consonant, e.g. 좋고 → 조코.
+fn aspirate_before(character: &ChoseongCharacter) -> Option<ChoseongCharacter> {
+    match character {
+        ChoseongCharacter::Giyeok => Some(ChoseongCharacter::Kiyeok),
+        ChoseongCharacter::Digeut => Some(ChoseongCharacter::Tieut),
+        ChoseongCharacter::Jieut => Some(ChoseongCharacter::Chieut),
+        ChoseongCharacter::Siot => Some(ChoseongCharacter::SsangSiot),
+        _ => None,
+    }
+}
+
+fn nasalize(character: &JongseongCharacter) -> JongseongCharacter {
+    match character {
+        JongseongCharacter::Giyeok => JongseongCharacter::Ieung,
+        JongseongCharacter::Digeut => JongseongCharacter::Nieun,
+        JongseongCharacter::Bieup => JongseongCharacter::Mieum,
+        _ => character.clone(),
+    }
+}
+
+/// Applies the sound changes between two syllables, except tensification.
+///
+/// When `aspirate_obstruents` is false, ㄱ, ㄷ and ㅂ followed by ㅎ are kept apart, e.g. 북한.
+pub(crate) fn assimilate(
+    jongseong: &JongseongCharacter,
+    choseong: &ChoseongCharacter,
+    jungseong: &JungseongCharacter,
+    aspirate_obstruents: bool,
+) -> (Option<JongseongCharacter>, ChoseongCharacter) {
+    let hieuh_rest = match jongseong {
+        JongseongCharacter::Hieuh => Some(None),
+        JongseongCharacter::NieunHieuh => Some(Some(JongseongCharacter::Nieun)),
+        JongseongCharacter::RieulHieuh => Some(Some(JongseongCharacter::Rieul)),
+        _ => None,
+    };
+
+    match choseong {
+        ChoseongCharacter::Ieung => {
+            let palatalized = match jongseong {
+                JongseongCharacter::Digeut if *jungseong == JungseongCharacter::I => {
+                    Some(ChoseongCharacter::Jieut)
+                }
+                JongseongCharacter::Tieut if *jungseong == JungseongCharacter::I => {
+                    Some(ChoseongCharacter::Chieut)
+                }
+                _ => None,
+            };
+
+            return match palatalized {
+                Some(palatalized) => (None, palatalized),
+                None => split(jongseong),
+            };
+        }
+        ChoseongCharacter::Hieuh if aspirate_obstruents => {
+            if let Some((rest, aspirated)) = aspirate_after(jongseong) {
+                return match aspirated {
+                    ChoseongCharacter::Tieut if *jungseong == JungseongCharacter::I => {
+                        (rest, ChoseongCharacter::Chieut)
+                    }
+                    _ => (rest, aspirated),
+                };
+            }
+        }
+        ChoseongCharacter::Hieuh => {}
+        _ => {
+            if let (Some(rest), Some(aspirated)) = (&hieuh_rest, aspirate_before(choseong)) {
+                return (rest.clone(), aspirated);
+            }
+        }
+    }
+
+    let jongseong = match (&hieuh_rest, choseong) {
+        (Some(None), ChoseongCharacter::Nieun) => JongseongCharacter::Nieun,
+        (Some(Some(rest)), _) => rest.clone(),
+        _ => neutralize(jongseong),
+    };
+
+    match (&jongseong, choseong) {
+        (JongseongCharacter::Rieul, ChoseongCharacter::Nieun)
+        | (JongseongCharacter::Nieun, ChoseongCharacter::Rieul) => {
+            (Some(JongseongCharacter::Rieul), ChoseongCharacter::Rieul)
+        }
+        (JongseongCharacter::Mieum, ChoseongCharacter::Rieul)
+        | (JongseongCharacter::Ieung, ChoseongCharacter::Rieul) => {
+            (Some(jongseong), ChoseongCharacter::Nieun)
+        }
+        (JongseongCharacter::Giyeok, ChoseongCharacter::Rieul)
+        | (JongseongCharacter::Digeut, ChoseongCharacter::Rieul)
+        | (JongseongCharacter::Bieup, ChoseongCharacter::Rieul) => {
+            (Some(nasalize(&jongseong)), ChoseongCharacter::Nieun)
+        }
+        (JongseongCharacter::Giyeok, ChoseongCharacter::Nieun)
+        | (JongseongCharacter::Digeut, ChoseongCharacter::Nieun)
+        | (JongseongCharacter::Bieup, ChoseongCharacter::Nieun)
+        | (JongseongCharacter::Giyeok, ChoseongCharacter::Mieum)
+        | (JongseongCharacter::Digeut, ChoseongCharacter::Mieum)
+        | (JongseongCharacter::Bieup, ChoseongCharacter::Mieum) => {
+            (Some(nasalize(&jongseong)), choseong.clone())
+        }
+        _ => (Some(jongseong), choseong.clone()),
+    }
+}
+
+/// Tenses ㄱ, ㄷ, ㅂ, ㅅ and ㅈ after ㄱ, ㄷ and ㅂ, e.g. 값이 → 갑씨, and after the
+/// compound finals of stems such as 앉고 and 넓게.
+///
+/// Tensification that depends on the word, e.g. 신고 or 갈등, is not applied.
+fn tensify(
+    original: &JongseongCharacter,
+    jongseong: &Option<JongseongCharacter>,
+    liaison: bool,
+    choseong: ChoseongCharacter,
+) -> ChoseongCharacter {
+    let tensing = match jongseong {
+        Some(jongseong) => {
+            matches!(
+                neutralize(jongseong),
+                JongseongCharacter::Giyeok | JongseongCharacter::Digeut | JongseongCharacter::Bieup
+            ) || !liaison
+                && matches!(
+                    original,
+                    JongseongCharacter::NieunJieut
+                        | JongseongCharacter::RieulMieum
+                        | JongseongCharacter::RieulBieup
+                        | JongseongCharacter::RieulTieut
+                )
+        }
+        None => false,
+    };
+
+    match choseong {
+        ChoseongCharacter::Giyeok if tensing => ChoseongCharacter::SsangGiyeok,
+        ChoseongCharacter::Digeut if tensing => ChoseongCharacter::SsangDigeut,
+        ChoseongCharacter::Bieup if tensing => ChoseongCharacter::SsangBieup,
+        ChoseongCharacter::Siot if tensing => ChoseongCharacter::SsangSiot,
+        ChoseongCharacter::Jieut if tensing => ChoseongCharacter::SsangJieut,
+        _ => choseong,
+    }
+}
+
+/// The pronounced form of a word given as syllables (표준 발음법), e.g. 국물 → 궁물.
+///
+/// Applies liaison, nasalization, liquidization, palatalization, aspiration and
+/// tensification between the syllables, then reduces every final to its representative sound.
+///
+/// The syllables are bare jamo, not the `Normal`/`Compat` enums `Choseong`, `Jungseong` and
+/// `Jongseong`, which convert into them with `From`.
+///
+/// See:
+/// * https://kornorms.korean.go.kr/regltn/regltnView.do?regltn_code=0002
+pub fn pronounce_syllables(
+    syllables: &[(
+        ChoseongCharacter,
+        JungseongCharacter,
+        Option<JongseongCharacter>,
+    )],
+) -> Vec<(
+    ChoseongCharacter,
+    JungseongCharacter,
+    Option<JongseongCharacter>,
+)> {
+    let mut pronounced = syllables.to_vec();
+
+    for index in 1..pronounced.len() {
+        let (before, after) = pronounced.split_at_mut(index);
+        let (_, _, jongseong) = &mut before[index - 1];
+        let (choseong, jungseong, _) = &mut after[0];
+        if let Some(original) = jongseong.clone() {
+            let liaison = *choseong == ChoseongCharacter::Ieung;
+            let (next_jongseong, next_choseong) = assimilate(&original, choseong, jungseong, true);
+            *choseong = tensify(&original, &next_jongseong, liaison, next_choseong);
+            *jongseong = next_jongseong;
+        }
+    }
+
+    for (_, _, jongseong) in pronounced.iter_mut() {
+        *jongseong = jongseong.as_ref().map(neutralize);
+    }
+
+    pronounced
+}
+
+/// The pronounced form of hangul text, e.g. 같이 먹어요 → 가치 머거요.
+///
+/// Sound changes apply within runs of syllables, not across spaces or other characters.
+pub fn pronounce(text: &str) -> String {
+    let mut pronounced = String::new();
+    let mut syllables = vec![];
+
+    let flush = |syllables: &mut Vec<(
+        ChoseongCharacter,
+        JungseongCharacter,
+        Option<JongseongCharacter>,
+    )>,
+                 pronounced: &mut String| {
+        pronounced.extend(
+            pronounce_syllables(syllables)
+                .into_iter()
+                .map(|syllable| char::from(Syllable::from(syllable))),
+        );
+        syllables.clear();
+    };
+
+    for character in text.chars() {
+        match Syllable::try_from(character) {
+            Ok(syllable) => syllables.push(syllable.into()),
+            Err(_) => {
+                flush(&mut syllables, &mut pronounced);
+                pronounced.push(character);
+            }
+        }
+    }
+    flush(&mut syllables, &mut pronounced);
+
+    pronounced
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn liaison() {
+        assert_eq!(pronounce("먹어요"), "머거요");
+        assert_eq!(pronounce("닭이"), "달기");
+        assert_eq!(pronounce("앉아"), "안자");
+        assert_eq!(pronounce("값이"), "갑씨");
+        assert_eq!(pronounce("좋아"), "조아");
+    }
+
+    #[test]
+    fn nasalization() {
+        assert_eq!(pronounce("국물"), "궁물");
+        assert_eq!(pronounce("닫는"), "단는");
+        assert_eq!(pronounce("밥물"), "밤물");
+        assert_eq!(pronounce("종로"), "종노");
+        assert_eq!(pronounce("독립"), "동닙");
+    }
+
+    #[test]
+    fn liquidization() {
+        assert_eq!(pronounce("신라"), "실라");
+        assert_eq!(pronounce("칼날"), "칼랄");
+    }
+
+    #[test]
+    fn palatalization() {
+        assert_eq!(pronounce("같이"), "가치");
+        assert_eq!(pronounce("굳이"), "구지");
+        assert_eq!(pronounce("굳히다"), "구치다");
+    }
+
+    #[test]
+    fn aspiration() {
+        assert_eq!(pronounce("좋고"), "조코");
+        assert_eq!(pronounce("축하"), "추카");
+        assert_eq!(pronounce("놓는"), "논는");
+    }
+
+    #[test]
+    fn tensification() {
+        assert_eq!(pronounce("국밥"), "국빱");
+        assert_eq!(pronounce("학교"), "학꾜");
+        assert_eq!(pronounce("옷고름"), "옫꼬름");
+        assert_eq!(pronounce("앉고"), "안꼬");
+        assert_eq!(pronounce("넓게"), "널께");
+    }
+
+    #[test]
+    fn neutralization() {
+        assert_eq!(pronounce("부엌"), "부억");
+        assert_eq!(pronounce("옷"), "옫");
+        assert_eq!(pronounce("앞"), "압");
+        assert_eq!(pronounce("닭"), "닥");
+        assert_eq!(pronounce("같이 먹어요, 앞!"), "가치 머거요, 압!");
+    }
+
+    #[test]
+    fn syllables() {
+        let syllables = vec![
+            (
+                ChoseongCharacter::Ieung,
+                JungseongCharacter::EO,
+                Some(JongseongCharacter::Bieup),
+            ),
+            (ChoseongCharacter::Ieung, JungseongCharacter::EO, None),
+        ];
+        assert_eq!(
+            pronounce_syllables(&syllables),
+            vec![
+                (ChoseongCharacter::Ieung, JungseongCharacter::EO, None),
+                (ChoseongCharacter::Bieup, JungseongCharacter::EO, None),
+            ]
+        );
+    }
+}
@@ -0,0 +1,212 @@
+use super::sound::*;
+use super::*;
+use crate::characters::*;
+
+/// See:
+/// * https://en.wikipedia.org/wiki/McCune–Reischauer
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum McCuneReischauerMode {
+    /// With breves and apostrophes. e.g. 평양 → p'yŏngyang
+    Diacritic,
+    /// Without breves and apostrophes. e.g. 평양 → pyongyang
+    Ascii,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct McCuneReischauerRomanization {
+    mode: McCuneReischauerMode,
+}
+
+fn jungseong_letters(character: &JungseongCharacter) -> &'static str {
+    match character {
+        JungseongCharacter::A => "a",
+        JungseongCharacter::AE => "ae",
+        JungseongCharacter::YA => "ya",
+        JungseongCharacter::YAE => "yae",
+        JungseongCharacter::EO => "ŏ",
+        JungseongCharacter::E => "e",
+        JungseongCharacter::YEO => "yŏ",
+        JungseongCharacter::YE => "ye",
+        JungseongCharacter::O => "o",
+        JungseongCharacter::WA => "wa",
+        JungseongCharacter::WAE => "wae",
+        JungseongCharacter::OE => "oe",
+        JungseongCharacter::YO => "yo",
+        JungseongCharacter::U => "u",
+        JungseongCharacter::WEO => "wŏ",
+        JungseongCharacter::WE => "we",
+        JungseongCharacter::WI => "wi",
+        JungseongCharacter::YU => "yu",
+        JungseongCharacter::EU => "ŭ",
+        JungseongCharacter::YI => "ŭi",
+        JungseongCharacter::I => "i",
+    }
+}
+
+/// ㄱ, ㄷ, ㅂ and ㅈ are voiced between voiced sounds, e.g. 부산 → pusan but 대구 → taegu.
+fn choseong_letters(
+    character: &ChoseongCharacter,
+    previous: Option<&Option<JongseongCharacter>>,
+) -> &'static str {
+    let previous = previous.map(|jongseong| jongseong.as_ref().map(neutralize));
+    let voiced = match &previous {
+        Some(None) => true,
+        Some(Some(jongseong)) => matches!(
+            jongseong,
+            JongseongCharacter::Nieun
+                | JongseongCharacter::Rieul
+                | JongseongCharacter::Mieum
+                | JongseongCharacter::Ieung
+        ),
+        None => false,
+    };
+
+    match character {
+        ChoseongCharacter::Giyeok if voiced => "g",
+        ChoseongCharacter::Giyeok => "k",
+        ChoseongCharacter::SsangGiyeok => "kk",
+        ChoseongCharacter::Nieun => "n",
+        ChoseongCharacter::Digeut if voiced => "d",
+        ChoseongCharacter::Digeut => "t",
+        ChoseongCharacter::SsangDigeut => "tt",
+        ChoseongCharacter::Rieul if previous == Some(Some(JongseongCharacter::Rieul)) => "l",
+        ChoseongCharacter::Rieul => "r",
+        ChoseongCharacter::Mieum => "m",
+        ChoseongCharacter::Bieup if voiced => "b",
+        ChoseongCharacter::Bieup => "p",
+        ChoseongCharacter::SsangBieup => "pp",
+        ChoseongCharacter::Siot => "s",
+        ChoseongCharacter::SsangSiot => "ss",
+        ChoseongCharacter::Ieung => "",
+        ChoseongCharacter::Jieut if voiced => "j",
+        ChoseongCharacter::Jieut => "ch",
+        ChoseongCharacter::SsangJieut => "tch",
+        ChoseongCharacter::Chieut => "ch'",
+        ChoseongCharacter::Kiyeok => "k'",
+        ChoseongCharacter::Tieut => "t'",
+        ChoseongCharacter::Pieup => "p'",
+        ChoseongCharacter::Hieuh => "h",
+    }
+}
+
+fn jongseong_letters(character: &JongseongCharacter) -> &'static str {
+    match neutralize(character) {
+        JongseongCharacter::Giyeok => "k",
+        JongseongCharacter::Nieun => "n",
+        JongseongCharacter::Digeut => "t",
+        JongseongCharacter::Rieul => "l",
+        JongseongCharacter::Mieum => "m",
+        JongseongCharacter::Bieup => "p",
+        _ => "ng",
+    }
+}
+
+impl McCuneReischauerRomanization {
+    pub fn new(mode: McCuneReischauerMode) -> Self {
+        McCuneReischauerRomanization { mode }
+    }
+
+    pub fn mode(&self) -> McCuneReischauerMode {
+        self.mode
+    }
+}
+
+impl Romanizer for McCuneReischauerRomanization {
+    fn romanize(&self, text: &str) -> String {
+        let mut tokens = tokenize(text);
+        assimilate_tokens(&mut tokens, false);
+
+        let mut romanized = String::new();
+        let mut previous: Option<Option<JongseongCharacter>> = None;
+
+        for token in tokens {
+            match token {
+                Token::Syllable(choseong, jungseong, jongseong) => {
+                    let mut letters = String::new();
+                    // Separates ㄴ + ㄱ from ㅇ, e.g. 한국 → han'guk.
+                    if previous == Some(Some(JongseongCharacter::Nieun))
+                        && choseong == ChoseongCharacter::Giyeok
+                    {
+                        letters.push('\'');
+                    }
+                    letters.push_str(choseong_letters(&choseong, previous.as_ref()));
+                    letters.push_str(jungseong_letters(&jungseong));
+                    if let Some(jongseong) = &jongseong {
+                        letters.push_str(jongseong_letters(jongseong));
+                    }
+
+                    match self.mode {
+                        McCuneReischauerMode::Diacritic => romanized.push_str(&letters),
+                        McCuneReischauerMode::Ascii => romanized.extend(
+                            letters.chars().filter(|character| *character != '\'').map(
+                                |character| match character {
+                                    'ŏ' => 'o',
+                                    'ŭ' => 'u',
+                                    _ => character,
+                                },
+                            ),
+                        ),
+                    }
+                    previous = Some(jongseong);
+                }
+                Token::Other(character) => {
+                    romanized.push(character);
+                    previous = None;
+                }
+            }
+        }
+
+        romanized
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diacritic(text: &str) -> String {
+        McCuneReischauerRomanization::new(McCuneReischauerMode::Diacritic).romanize(text)
+    }
+
+    fn ascii(text: &str) -> String {
+        McCuneReischauerRomanization::new(McCuneReischauerMode::Ascii).romanize(text)
+    }
+
+    #[test]
+    fn voicing() {
+        assert_eq!(diacritic("부산"), "pusan");
+        assert_eq!(diacritic("대구"), "taegu");
+        assert_eq!(diacritic("광주"), "kwangju");
+        assert_eq!(diacritic("제주"), "cheju");
+        assert_eq!(diacritic("학교"), "hakkyo");
+        assert_eq!(diacritic("식당"), "siktang");
+    }
+
+    #[test]
+    fn breves_and_apostrophes() {
+        assert_eq!(diacritic("서울"), "sŏul");
+        assert_eq!(diacritic("평양"), "p'yŏngyang");
+        assert_eq!(diacritic("청주"), "ch'ŏngju");
+        assert_eq!(diacritic("김치"), "kimch'i");
+        assert_eq!(diacritic("한국"), "han'guk");
+        assert_eq!(diacritic("어머니"), "ŏmŏni");
+        assert_eq!(diacritic("의사"), "ŭisa");
+    }
+
+    #[test]
+    fn sound_changes() {
+        assert_eq!(diacritic("독립문"), "tongnimmun");
+        assert_eq!(diacritic("신라"), "silla");
+        assert_eq!(diacritic("북한"), "pukhan");
+        assert_eq!(diacritic("좋고"), "chok'o");
+        assert_eq!(diacritic("시장"), "sijang");
+    }
+
+    #[test]
+    fn ascii_mode() {
+        assert_eq!(ascii("서울"), "soul");
+        assert_eq!(ascii("평양"), "pyongyang");
+        assert_eq!(ascii("한국"), "hanguk");
+        assert_eq!(ascii("김치, don't"), "kimchi, don't");
+    }
+}
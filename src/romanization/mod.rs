@@ -0,0 +1,105 @@
+pub mod mccune_reischauer;
+pub mod reverse;
+pub mod revised;
+mod sound;
+pub mod yale;
+
+pub use mccune_reischauer::*;
+pub use reverse::*;
+pub use revised::*;
+pub use yale::*;
+
+use crate::characters::*;
+use crate::syllable::*;
+use std::convert::TryFrom;
+
+pub trait Romanizer {
+    fn romanize(&self, text: &str) -> String;
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Token {
+    Syllable(
+        ChoseongCharacter,
+        JungseongCharacter,
+        Option<JongseongCharacter>,
+    ),
+    Other(char),
+}
+
+pub(crate) fn tokenize(text: &str) -> Vec<Token> {
+    text.chars()
+        .map(|character| match Syllable::try_from(character) {
+            Ok(syllable) => {
+                let (choseong, jungseong, jongseong) = syllable.into();
+                Token::Syllable(choseong, jungseong, jongseong)
+            }
+            Err(_) => Token::Other(character),
+        })
+        .collect()
+}
+
+pub(crate) fn capitalize(text: &str) -> String {
+    let mut characters = text.chars();
+    match characters.next() {
+        Some(first) => first.to_uppercase().chain(characters).collect(),
+        None => String::new(),
+    }
+}
+
+/// Whether the letters around a syllable boundary could be split in another way,
+/// e.g. `eoss` + `seub` and `eos` + `sseub`, or `lk` + a silent ㅇ and `l` + `k`.
+///
+/// `choseong_letters` need not list the empty letters of the silent ㅇ. A consonant before a vowel
+/// reads as an initial, so the whole being a final only counts when the choseong is the silent ㅇ.
+pub(crate) fn is_ambiguous(
+    jongseong: &str,
+    choseong: &str,
+    jongseong_letters: &[&str],
+    choseong_letters: &[&str],
+) -> bool {
+    let joined = format!("{}{}", jongseong, choseong);
+    let splits = (0..=joined.len())
+        .filter(|index| {
+            let (jongseong, rest) = joined.split_at(*index);
+            let initial = if rest.is_empty() {
+                choseong.is_empty()
+            } else {
+                choseong_letters.contains(&rest)
+            };
+            jongseong_letters.contains(&jongseong) && initial
+        })
+        .count();
+
+    splits > 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ambiguous() {
+        assert!(is_ambiguous("ss", "s", &YALE_JONGSEONG, &YALE_CHOSEONG));
+        assert!(is_ambiguous("lk", "", &YALE_JONGSEONG, &YALE_CHOSEONG));
+        assert!(!is_ambiguous("l", "k", &YALE_JONGSEONG, &YALE_CHOSEONG));
+        assert!(!is_ambiguous("", "s", &YALE_JONGSEONG, &YALE_CHOSEONG));
+        assert!(!is_ambiguous("", "", &YALE_JONGSEONG, &YALE_CHOSEONG));
+    }
+
+    #[test]
+    fn romanizer() {
+        let romanizers: Vec<Box<dyn Romanizer>> = vec![
+            Box::new(RevisedRomanization::new(RevisedMode::Pronunciation)),
+            Box::new(McCuneReischauerRomanization::new(
+                McCuneReischauerMode::Diacritic,
+            )),
+            Box::new(YaleRomanization::new()),
+        ];
+        let romanized: Vec<String> = romanizers
+            .iter()
+            .map(|romanizer| romanizer.romanize("평양"))
+            .collect();
+        assert_eq!(romanized, vec!["pyeongyang", "p'yŏngyang", "phyengyang"]);
+    }
+}
@@ -0,0 +1,510 @@
+use crate::characters::*;
+use crate::syllable::*;
+use std::collections::HashSet;
+
+const MAX_CANDIDATES: usize = 16;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DeromanizeScheme {
+    /// Revised Romanization, in either mode.
+    Revised,
+    Yale,
+}
+
+/// Letters, the jamo they are read as, and a penalty for unlikely readings.
+struct Letters {
+    choseong: &'static [(&'static str, ChoseongCharacter, u32)],
+    jungseong: &'static [(&'static str, JungseongCharacter, u32)],
+    jongseong: &'static [(&'static str, JongseongCharacter, u32)],
+}
+
+const REVISED: Letters = Letters {
+    choseong: &[
+        ("", ChoseongCharacter::Ieung, 0),
+        ("g", ChoseongCharacter::Giyeok, 0),
+        ("kk", ChoseongCharacter::SsangGiyeok, 0),
+        ("n", ChoseongCharacter::Nieun, 0),
+        ("d", ChoseongCharacter::Digeut, 0),
+        ("tt", ChoseongCharacter::SsangDigeut, 0),
+        ("r", ChoseongCharacter::Rieul, 0),
+        ("l", ChoseongCharacter::Rieul, 0),
+        ("m", ChoseongCharacter::Mieum, 0),
+        ("b", ChoseongCharacter::Bieup, 0),
+        ("pp", ChoseongCharacter::SsangBieup, 0),
+        ("s", ChoseongCharacter::Siot, 0),
+        ("ss", ChoseongCharacter::SsangSiot, 0),
+        ("j", ChoseongCharacter::Jieut, 0),
+        ("jj", ChoseongCharacter::SsangJieut, 0),
+        ("ch", ChoseongCharacter::Chieut, 0),
+        ("k", ChoseongCharacter::Kiyeok, 0),
+        ("k", ChoseongCharacter::Giyeok, 1),
+        ("t", ChoseongCharacter::Tieut, 0),
+        ("t", ChoseongCharacter::Digeut, 1),
+        ("p", ChoseongCharacter::Pieup, 0),
+        ("p", ChoseongCharacter::Bieup, 1),
+        ("h", ChoseongCharacter::Hieuh, 0),
+    ],
+    jungseong: &[
+        ("a", JungseongCharacter::A, 0),
+        ("ae", JungseongCharacter::AE, 0),
+        ("ya", JungseongCharacter::YA, 0),
+        ("yae", JungseongCharacter::YAE, 0),
+        ("eo", JungseongCharacter::EO, 0),
+        ("e", JungseongCharacter::E, 0),
+        ("yeo", JungseongCharacter::YEO, 0),
+        ("ye", JungseongCharacter::YE, 0),
+        ("o", JungseongCharacter::O, 0),
+        ("wa", JungseongCharacter::WA, 0),
+        ("wae", JungseongCharacter::WAE, 0),
+        ("oe", JungseongCharacter::OE, 0),
+        ("yo", JungseongCharacter::YO, 0),
+        ("u", JungseongCharacter::U, 0),
+        ("wo", JungseongCharacter::WEO, 0),
+        ("we", JungseongCharacter::WE, 0),
+        ("wi", JungseongCharacter::WI, 0),
+        ("yu", JungseongCharacter::YU, 0),
+        ("eu", JungseongCharacter::EU, 0),
+        ("ui", JungseongCharacter::YI, 0),
+        ("i", JungseongCharacter::I, 0),
+    ],
+    jongseong: &[
+        ("k", JongseongCharacter::Giyeok, 0),
+        ("g", JongseongCharacter::Giyeok, 1),
+        ("kk", JongseongCharacter::SsangGiyeok, 1),
+        ("n", JongseongCharacter::Nieun, 0),
+        ("t", JongseongCharacter::Siot, 0),
+        ("t", JongseongCharacter::Digeut, 1),
+        ("d", JongseongCharacter::Digeut, 1),
+        ("l", JongseongCharacter::Rieul, 0),
+        ("m", JongseongCharacter::Mieum, 0),
+        ("p", JongseongCharacter::Bieup, 0),
+        ("b", JongseongCharacter::Bieup, 1),
+        ("ng", JongseongCharacter::Ieung, 0),
+        // Finals only the transliteration mode writes, e.g. 값 gabs and 좋다 johda.
+        ("gs", JongseongCharacter::GiyeokSiot, 1),
+        ("nj", JongseongCharacter::NieunJieut, 1),
+        ("nh", JongseongCharacter::NieunHieuh, 1),
+        ("lg", JongseongCharacter::RieulGiyeok, 1),
+        ("lm", JongseongCharacter::RieulMieum, 1),
+        ("lb", JongseongCharacter::RieulBieup, 1),
+        ("ls", JongseongCharacter::RieulSiot, 1),
+        ("lt", JongseongCharacter::RieulTieut, 1),
+        ("lp", JongseongCharacter::RieulPieup, 1),
+        ("lh", JongseongCharacter::RieulHieuh, 1),
+        ("bs", JongseongCharacter::BieupSiot, 1),
+        ("s", JongseongCharacter::Siot, 1),
+        ("ss", JongseongCharacter::SsangSiot, 1),
+        ("j", JongseongCharacter::Jieut, 1),
+        ("ch", JongseongCharacter::Chieut, 1),
+        ("k", JongseongCharacter::Kieuk, 1),
+        ("t", JongseongCharacter::Tieut, 1),
+        ("p", JongseongCharacter::Pieup, 1),
+        ("h", JongseongCharacter::Hieuh, 1),
+    ],
+};
+
+const YALE: Letters = Letters {
+    choseong: &[
+        ("", ChoseongCharacter::Ieung, 0),
+        ("k", ChoseongCharacter::Giyeok, 0),
+        ("kk", ChoseongCharacter::SsangGiyeok, 0),
+        ("n", ChoseongCharacter::Nieun, 0),
+        ("t", ChoseongCharacter::Digeut, 0),
+        ("tt", ChoseongCharacter::SsangDigeut, 0),
+        ("l", ChoseongCharacter::Rieul, 0),
+        ("m", ChoseongCharacter::Mieum, 0),
+        ("p", ChoseongCharacter::Bieup, 0),
+        ("pp", ChoseongCharacter::SsangBieup, 0),
+        ("s", ChoseongCharacter::Siot, 0),
+        ("ss", ChoseongCharacter::SsangSiot, 0),
+        ("c", ChoseongCharacter::Jieut, 0),
+        ("cc", ChoseongCharacter::SsangJieut, 0),
+        ("ch", ChoseongCharacter::Chieut, 0),
+        ("kh", ChoseongCharacter::Kiyeok, 0),
+        ("th", ChoseongCharacter::Tieut, 0),
+        ("ph", ChoseongCharacter::Pieup, 0),
+        ("h", ChoseongCharacter::Hieuh, 0),
+    ],
+    jungseong: &[
+        ("a", JungseongCharacter::A, 0),
+        ("ay", JungseongCharacter::AE, 0),
+        ("ya", JungseongCharacter::YA, 0),
+        ("yay", JungseongCharacter::YAE, 0),
+        ("e", JungseongCharacter::EO, 0),
+        ("ey", JungseongCharacter::E, 0),
+        ("ye", JungseongCharacter::YEO, 0),
+        ("yey", JungseongCharacter::YE, 0),
+        ("o", JungseongCharacter::O, 0),
+        ("wa", JungseongCharacter::WA, 0),
+        ("way", JungseongCharacter::WAE, 0),
+        ("oy", JungseongCharacter::OE, 0),
+        ("yo", JungseongCharacter::YO, 0),
+        ("wu", JungseongCharacter::U, 0),
+        ("we", JungseongCharacter::WEO, 0),
+        ("wey", JungseongCharacter::WE, 0),
+        ("wi", JungseongCharacter::WI, 0),
+        ("yu", JungseongCharacter::YU, 0),
+        ("u", JungseongCharacter::EU, 0),
+        // ㅜ after ㅁ, ㅂ, ㅃ and ㅍ
+        ("u", JungseongCharacter::U, 0),
+        ("uy", JungseongCharacter::YI, 0),
+        ("i", JungseongCharacter::I, 0),
+    ],
+    jongseong: &[
+        ("k", JongseongCharacter::Giyeok, 0),
+        ("kk", JongseongCharacter::SsangGiyeok, 0),
+        ("ks", JongseongCharacter::GiyeokSiot, 0),
+        ("n", JongseongCharacter::Nieun, 0),
+        ("nc", JongseongCharacter::NieunJieut, 0),
+        ("nh", JongseongCharacter::NieunHieuh, 0),
+        ("t", JongseongCharacter::Digeut, 0),
+        ("l", JongseongCharacter::Rieul, 0),
+        ("lk", JongseongCharacter::RieulGiyeok, 0),
+        ("lm", JongseongCharacter::RieulMieum, 0),
+        ("lp", JongseongCharacter::RieulBieup, 0),
+        ("ls", JongseongCharacter::RieulSiot, 0),
+        ("lth", JongseongCharacter::RieulTieut, 0),
+        ("lph", JongseongCharacter::RieulPieup, 0),
+        ("lh", JongseongCharacter::RieulHieuh, 0),
+        ("m", JongseongCharacter::Mieum, 0),
+        ("p", JongseongCharacter::Bieup, 0),
+        ("ps", JongseongCharacter::BieupSiot, 0),
+        ("s", JongseongCharacter::Siot, 0),
+        ("ss", JongseongCharacter::SsangSiot, 0),
+        ("ng", JongseongCharacter::Ieung, 0),
+        ("c", JongseongCharacter::Jieut, 0),
+        ("ch", JongseongCharacter::Chieut, 0),
+        ("kh", JongseongCharacter::Kieuk, 0),
+        ("th", JongseongCharacter::Tieut, 0),
+        ("ph", JongseongCharacter::Pieup, 0),
+        ("h", JongseongCharacter::Hieuh, 0),
+    ],
+};
+
+fn is_labial(character: &ChoseongCharacter) -> bool {
+    matches!(
+        character,
+        ChoseongCharacter::Mieum
+            | ChoseongCharacter::Bieup
+            | ChoseongCharacter::SsangBieup
+            | ChoseongCharacter::Pieup
+    )
+}
+
+#[derive(Clone, Debug)]
+struct Candidate {
+    text: String,
+    penalty: u32,
+}
+
+/// A reading of the letters up to a position, with the jongseong it ends in.
+#[derive(Clone, Debug)]
+struct State {
+    candidate: Candidate,
+    previous: Option<JongseongCharacter>,
+}
+
+/// Keeps the `MAX_CANDIDATES` most likely candidates, dropping repeated texts.
+fn prune<T>(items: &mut Vec<T>, candidate: impl Fn(&T) -> &Candidate) {
+    items.sort_by_key(|item| candidate(item).penalty);
+    let mut seen = HashSet::new();
+    items.retain(|item| seen.insert(candidate(item).text.clone()));
+    items.truncate(MAX_CANDIDATES);
+}
+
+struct Parser<'a> {
+    letters: &'a Letters,
+    scheme: DeromanizeScheme,
+}
+
+impl Parser<'_> {
+    /// Reads the syllables that start at `state` into the states at the positions they end.
+    fn read(&self, rest: &str, position: usize, state: &State, states: &mut [Vec<State>]) {
+        let mut push = |length: usize,
+                        syllable: Syllable,
+                        penalty: u32,
+                        jongseong: Option<&JongseongCharacter>| {
+            let mut text = state.candidate.text.clone();
+            text.push(syllable.into());
+            states[position + length].push(State {
+                candidate: Candidate { text, penalty },
+                previous: jongseong.cloned(),
+            });
+        };
+
+        for (choseong_letters, choseong, choseong_penalty) in self.letters.choseong {
+            let after_choseong = match rest.strip_prefix(choseong_letters) {
+                Some(after_choseong) => after_choseong,
+                None => continue,
+            };
+            // A consonant before a vowel would have been written as the next initial.
+            let liaison_penalty = match (choseong, &state.previous) {
+                (ChoseongCharacter::Ieung, Some(JongseongCharacter::Ieung)) => 1,
+                (ChoseongCharacter::Ieung, Some(_)) => 2,
+                _ => 0,
+            };
+
+            for (jungseong_letters, jungseong, jungseong_penalty) in self.letters.jungseong {
+                let after_jungseong = match after_choseong.strip_prefix(jungseong_letters) {
+                    Some(after_jungseong) => after_jungseong,
+                    None => continue,
+                };
+                // Yale `u` is ㅜ after labials, and ㅡ anywhere else.
+                let jungseong_penalty = match (self.scheme, jungseong, *jungseong_letters) {
+                    (DeromanizeScheme::Yale, JungseongCharacter::U, "u")
+                        if !is_labial(choseong) =>
+                    {
+                        continue
+                    }
+                    (DeromanizeScheme::Yale, JungseongCharacter::EU, "u")
+                        if is_labial(choseong) =>
+                    {
+                        1
+                    }
+                    _ => *jungseong_penalty,
+                };
+
+                let penalty = state.candidate.penalty
+                    + 1
+                    + choseong_penalty
+                    + liaison_penalty
+                    + jungseong_penalty;
+                push(
+                    rest.len() - after_jungseong.len(),
+                    (choseong.clone(), jungseong.clone(), None).into(),
+                    penalty,
+                    None,
+                );
+
+                for (jongseong_letters, jongseong, jongseong_penalty) in self.letters.jongseong {
+                    let after_jongseong = match after_jungseong.strip_prefix(jongseong_letters) {
+                        Some(after_jongseong) => after_jongseong,
+                        None => continue,
+                    };
+
+                    push(
+                        rest.len() - after_jongseong.len(),
+                        (choseong.clone(), jungseong.clone(), Some(jongseong.clone())).into(),
+                        penalty + jongseong_penalty,
+                        Some(jongseong),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Reads `segment` into syllables, keeping only the most likely readings at each position
+    /// so that long words take linear time.
+    fn parse(&self, segment: &str) -> Vec<Candidate> {
+        let mut states: Vec<Vec<State>> = vec![vec![]; segment.len() + 1];
+        states[0].push(State {
+            candidate: Candidate {
+                text: String::new(),
+                penalty: 0,
+            },
+            previous: None,
+        });
+
+        for position in 0..segment.len() {
+            let mut current = std::mem::take(&mut states[position]);
+            prune(&mut current, |state| &state.candidate);
+            for state in &current {
+                self.read(&segment[position..], position, state, &mut states);
+            }
+        }
+
+        let mut candidates: Vec<Candidate> = states
+            .pop()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|state| state.candidate)
+            .collect();
+        prune(&mut candidates, |candidate| candidate);
+        candidates
+    }
+}
+
+fn deromanize_segment(segment: &str, scheme: DeromanizeScheme) -> Vec<Candidate> {
+    let letters = match scheme {
+        DeromanizeScheme::Revised => &REVISED,
+        DeromanizeScheme::Yale => &YALE,
+    };
+    let parser = Parser { letters, scheme };
+    parser.parse(&segment.to_ascii_lowercase())
+}
+
+fn combine(left: Vec<Candidate>, right: Vec<Candidate>) -> Vec<Candidate> {
+    let mut combined: Vec<Candidate> = left
+        .iter()
+        .flat_map(|left| {
+            right.iter().map(move |right| Candidate {
+                text: format!("{}{}", left.text, right.text),
+                penalty: left.penalty + right.penalty,
+            })
+        })
+        .collect();
+    prune(&mut combined, |candidate| candidate);
+
+    combined
+}
+
+/// Reads romanized text back into hangul, returning candidates from the most likely.
+///
+/// `-`, `'` and `.` between letters mark syllable boundaries, e.g. `hang-eul` → 항을.
+/// Returns no candidates when a word cannot be read as hangul.
+pub fn deromanize(text: &str, scheme: DeromanizeScheme) -> Vec<String> {
+    let mut candidates = vec![Candidate {
+        text: String::new(),
+        penalty: 0,
+    }];
+    let mut characters = text.char_indices().peekable();
+
+    while let Some((start, character)) = characters.next() {
+        if !character.is_ascii_alphabetic() {
+            candidates = combine(
+                candidates,
+                vec![Candidate {
+                    text: character.to_string(),
+                    penalty: 0,
+                }],
+            );
+            continue;
+        }
+
+        let mut end = start + character.len_utf8();
+        while let Some((index, character)) = characters.peek() {
+            if !character.is_ascii_alphabetic() {
+                break;
+            }
+            end = index + character.len_utf8();
+            characters.next();
+        }
+
+        let segment = deromanize_segment(&text[start..end], scheme);
+        if segment.is_empty() {
+            return vec![];
+        }
+        candidates = combine(candidates, segment);
+
+        // Separators between letters are dropped.
+        let mut lookahead = characters.clone();
+        if let Some((_, '-')) | Some((_, '\'')) | Some((_, '.')) = lookahead.next() {
+            if let Some((_, next)) = lookahead.next() {
+                if next.is_ascii_alphabetic() {
+                    characters.next();
+                }
+            }
+        }
+    }
+
+    candidates
+        .into_iter()
+        .map(|candidate| candidate.text)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::romanization::*;
+
+    fn revised(text: &str) -> Vec<String> {
+        deromanize(text, DeromanizeScheme::Revised)
+    }
+
+    #[test]
+    fn revised_words() {
+        assert_eq!(revised("hanguk")[0], "한국");
+        assert_eq!(revised("seoul")[0], "서울");
+        assert_eq!(revised("Busan")[0], "부산");
+        assert_eq!(revised("gimchi")[0], "김치");
+        assert_eq!(revised("silla")[0], "실라");
+    }
+
+    #[test]
+    fn revised_ambiguous() {
+        let candidates = revised("hangeul");
+        assert_eq!(candidates[0], "한글");
+        assert!(candidates.contains(&"항을".to_string()));
+
+        let candidates = revised("gaeul");
+        assert!(candidates.contains(&"가을".to_string()));
+        assert!(candidates.contains(&"개울".to_string()));
+    }
+
+    #[test]
+    fn revised_hyphen() {
+        assert_eq!(revised("hang-eul")[0], "항을");
+        assert_eq!(revised("jung-ang")[0], "중앙");
+        assert_eq!(revised("ban-gudae")[0], "반구대");
+    }
+
+    #[test]
+    fn revised_name() {
+        let candidates = revised("Hong Gildong");
+        assert_eq!(candidates[0], "홍 길동");
+
+        let candidates = revised("Kim");
+        assert!(candidates.contains(&"킴".to_string()));
+        assert!(candidates.contains(&"김".to_string()));
+    }
+
+    #[test]
+    fn revised_transliteration() {
+        assert_eq!(revised("gabs")[0], "값");
+        assert_eq!(revised("johda")[0], "좋다");
+        assert_eq!(revised("eobs-eoss-seubnida")[0], "없었습니다");
+        assert_eq!(revised("buskkoch")[0], "붓꽃");
+        assert_eq!(revised("dalg")[0], "닭");
+    }
+
+    #[test]
+    fn revised_round_trip() {
+        let romanization = RevisedRomanization::new(RevisedMode::Transliteration);
+        for word in [
+            "값",
+            "좋다",
+            "없었습니다",
+            "붓꽃",
+            "닭",
+            "읽다",
+            "앉다",
+            "부엌",
+            "밭",
+            "가까",
+            "갘카",
+        ]
+        .iter()
+        {
+            let candidates = revised(&romanization.romanize(word));
+            assert!(candidates.contains(&word.to_string()), "{}", word);
+        }
+    }
+
+    #[test]
+    fn yale() {
+        assert_eq!(deromanize("hankwuk", DeromanizeScheme::Yale)[0], "한국");
+        assert_eq!(deromanize("pusan", DeromanizeScheme::Yale)[0], "부산");
+        assert_eq!(deromanize("kaps", DeromanizeScheme::Yale)[0], "값");
+        assert_eq!(deromanize("puk.han", DeromanizeScheme::Yale)[0], "북한");
+    }
+
+    #[test]
+    fn not_hangul() {
+        assert!(revised("xyz").is_empty());
+        assert_eq!(revised("")[0], "");
+        assert_eq!(revised("1, 2")[0], "1, 2");
+    }
+
+    #[test]
+    fn long_input() {
+        // Every position keeps at most `MAX_CANDIDATES` readings, however many splits there are.
+        let candidates = deromanize_segment(&"eui".repeat(100), DeromanizeScheme::Revised);
+        assert_eq!(candidates.len(), MAX_CANDIDATES);
+        assert_eq!(candidates[0].text.chars().count(), 200);
+
+        let candidates = revised(&"eui eui-".repeat(50));
+        assert!(!candidates.is_empty());
+        assert!(candidates.len() <= MAX_CANDIDATES);
+    }
+}
@@ -0,0 +1,346 @@
+use super::sound::*;
+use super::*;
+use crate::characters::*;
+
+/// See:
+/// * https://www.korean.go.kr/front_eng/roman/roman_01.do
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RevisedMode {
+    /// Letter by letter, so that the hangul can be restored. e.g. 신라 → sinla
+    Transliteration,
+    /// Reflects the sound changes between syllables. e.g. 신라 → silla
+    Pronunciation,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RevisedRomanization {
+    mode: RevisedMode,
+}
+
+fn choseong_letters(character: &ChoseongCharacter) -> &'static str {
+    match character {
+        ChoseongCharacter::Giyeok => "g",
+        ChoseongCharacter::SsangGiyeok => "kk",
+        ChoseongCharacter::Nieun => "n",
+        ChoseongCharacter::Digeut => "d",
+        ChoseongCharacter::SsangDigeut => "tt",
+        ChoseongCharacter::Rieul => "r",
+        ChoseongCharacter::Mieum => "m",
+        ChoseongCharacter::Bieup => "b",
+        ChoseongCharacter::SsangBieup => "pp",
+        ChoseongCharacter::Siot => "s",
+        ChoseongCharacter::SsangSiot => "ss",
+        ChoseongCharacter::Ieung => "",
+        ChoseongCharacter::Jieut => "j",
+        ChoseongCharacter::SsangJieut => "jj",
+        ChoseongCharacter::Chieut => "ch",
+        ChoseongCharacter::Kiyeok => "k",
+        ChoseongCharacter::Tieut => "t",
+        ChoseongCharacter::Pieup => "p",
+        ChoseongCharacter::Hieuh => "h",
+    }
+}
+
+fn jungseong_letters(character: &JungseongCharacter) -> &'static str {
+    match character {
+        JungseongCharacter::A => "a",
+        JungseongCharacter::AE => "ae",
+        JungseongCharacter::YA => "ya",
+        JungseongCharacter::YAE => "yae",
+        JungseongCharacter::EO => "eo",
+        JungseongCharacter::E => "e",
+        JungseongCharacter::YEO => "yeo",
+        JungseongCharacter::YE => "ye",
+        JungseongCharacter::O => "o",
+        JungseongCharacter::WA => "wa",
+        JungseongCharacter::WAE => "wae",
+        JungseongCharacter::OE => "oe",
+        JungseongCharacter::YO => "yo",
+        JungseongCharacter::U => "u",
+        JungseongCharacter::WEO => "wo",
+        JungseongCharacter::WE => "we",
+        JungseongCharacter::WI => "wi",
+        JungseongCharacter::YU => "yu",
+        JungseongCharacter::EU => "eu",
+        JungseongCharacter::YI => "ui",
+        JungseongCharacter::I => "i",
+    }
+}
+
+fn jongseong_letters(character: &JongseongCharacter) -> &'static str {
+    match character {
+        JongseongCharacter::Giyeok => "g",
+        JongseongCharacter::SsangGiyeok => "kk",
+        JongseongCharacter::GiyeokSiot => "gs",
+        JongseongCharacter::Nieun => "n",
+        JongseongCharacter::NieunJieut => "nj",
+        JongseongCharacter::NieunHieuh => "nh",
+        JongseongCharacter::Digeut => "d",
+        JongseongCharacter::Rieul => "l",
+        JongseongCharacter::RieulGiyeok => "lg",
+        JongseongCharacter::RieulMieum => "lm",
+        JongseongCharacter::RieulBieup => "lb",
+        JongseongCharacter::RieulSiot => "ls",
+        JongseongCharacter::RieulTieut => "lt",
+        JongseongCharacter::RieulPieup => "lp",
+        JongseongCharacter::RieulHieuh => "lh",
+        JongseongCharacter::Mieum => "m",
+        JongseongCharacter::Bieup => "b",
+        JongseongCharacter::BieupSiot => "bs",
+        JongseongCharacter::Siot => "s",
+        JongseongCharacter::SsangSiot => "ss",
+        JongseongCharacter::Ieung => "ng",
+        JongseongCharacter::Jieut => "j",
+        JongseongCharacter::Chieut => "ch",
+        JongseongCharacter::Kieuk => "k",
+        JongseongCharacter::Tieut => "t",
+        JongseongCharacter::Pieup => "p",
+        JongseongCharacter::Hieuh => "h",
+    }
+}
+
+fn neutralized_letters(character: &JongseongCharacter) -> &'static str {
+    match neutralize(character) {
+        JongseongCharacter::Giyeok => "k",
+        JongseongCharacter::Nieun => "n",
+        JongseongCharacter::Digeut => "t",
+        JongseongCharacter::Rieul => "l",
+        JongseongCharacter::Mieum => "m",
+        JongseongCharacter::Bieup => "p",
+        _ => "ng",
+    }
+}
+
+impl RevisedRomanization {
+    pub fn new(mode: RevisedMode) -> Self {
+        RevisedRomanization { mode }
+    }
+
+    pub fn mode(&self) -> RevisedMode {
+        self.mode
+    }
+
+    /// Romanizes a personal name, e.g. 홍길동 → Hong Gildong.
+    ///
+    /// Sound changes inside the given name are not reflected.
+    pub fn name(surname: &str, given_name: &str) -> String {
+        let romanize = |text: &str| -> String {
+            tokenize(text)
+                .iter()
+                .map(|token| match token {
+                    Token::Syllable(choseong, jungseong, jongseong) => {
+                        let mut letters = String::new();
+                        letters.push_str(choseong_letters(choseong));
+                        letters.push_str(jungseong_letters(jungseong));
+                        if let Some(jongseong) = jongseong {
+                            letters.push_str(neutralized_letters(jongseong));
+                        }
+                        letters
+                    }
+                    Token::Other(character) => character.to_string(),
+                })
+                .collect()
+        };
+
+        format!(
+            "{} {}",
+            capitalize(&romanize(surname)),
+            capitalize(&romanize(given_name))
+        )
+    }
+}
+
+impl Romanizer for RevisedRomanization {
+    fn romanize(&self, text: &str) -> String {
+        match self.mode {
+            RevisedMode::Transliteration => transliterate(text),
+            RevisedMode::Pronunciation => pronounce(text),
+        }
+    }
+}
+
+const TRANSLITERATED_CHOSEONG: [&str; 18] = [
+    "g", "kk", "n", "d", "tt", "l", "m", "b", "pp", "s", "ss", "j", "jj", "ch", "k", "t", "p", "h",
+];
+
+const TRANSLITERATED_JONGSEONG: [&str; 28] = [
+    "", "g", "kk", "gs", "n", "nj", "nh", "d", "l", "lg", "lm", "lb", "ls", "lt", "lp", "lh", "m",
+    "b", "bs", "s", "ss", "ng", "j", "ch", "k", "t", "p", "h",
+];
+
+/// Separates syllables with `-` before a silent ㅇ, e.g. 물엿 → mul-yeos, and after a final
+/// that could also be read with the next initial, e.g. 없었습니다 → eobs-eoss-seubnida.
+/// Letters after a syllable without a final always read as the initial, so 가까 is gakka.
+fn transliterate(text: &str) -> String {
+    let mut romanized = String::new();
+    let mut previous: Option<&str> = None;
+
+    for token in tokenize(text) {
+        match token {
+            Token::Syllable(choseong, jungseong, jongseong) => {
+                let letters = match choseong {
+                    ChoseongCharacter::Rieul => "l",
+                    _ => choseong_letters(&choseong),
+                };
+                match previous {
+                    Some(_) if letters.is_empty() => romanized.push('-'),
+                    Some(previous)
+                        if !previous.is_empty()
+                            && is_ambiguous(
+                                previous,
+                                letters,
+                                &TRANSLITERATED_JONGSEONG,
+                                &TRANSLITERATED_CHOSEONG,
+                            ) =>
+                    {
+                        romanized.push('-')
+                    }
+                    _ => {}
+                }
+                romanized.push_str(letters);
+                romanized.push_str(jungseong_letters(&jungseong));
+
+                let letters = jongseong.as_ref().map_or("", jongseong_letters);
+                romanized.push_str(letters);
+                previous = Some(letters);
+            }
+            Token::Other(character) => {
+                romanized.push(character);
+                previous = None;
+            }
+        }
+    }
+
+    romanized
+}
+
+fn pronounce(text: &str) -> String {
+    let mut tokens = tokenize(text);
+    assimilate_tokens(&mut tokens, true);
+
+    let mut romanized = String::new();
+    let mut after_rieul = false;
+
+    for token in tokens {
+        match token {
+            Token::Syllable(choseong, jungseong, jongseong) => {
+                match choseong {
+                    ChoseongCharacter::Rieul if after_rieul => romanized.push('l'),
+                    _ => romanized.push_str(choseong_letters(&choseong)),
+                }
+                romanized.push_str(jungseong_letters(&jungseong));
+                if let Some(jongseong) = &jongseong {
+                    romanized.push_str(neutralized_letters(jongseong));
+                }
+                after_rieul = jongseong.map(|jongseong| neutralize(&jongseong))
+                    == Some(JongseongCharacter::Rieul);
+            }
+            Token::Other(character) => {
+                romanized.push(character);
+                after_rieul = false;
+            }
+        }
+    }
+
+    romanized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pronounce(text: &str) -> String {
+        RevisedRomanization::new(RevisedMode::Pronunciation).romanize(text)
+    }
+
+    fn transliterate(text: &str) -> String {
+        RevisedRomanization::new(RevisedMode::Transliteration).romanize(text)
+    }
+
+    #[test]
+    fn pronunciation_letters() {
+        assert_eq!(pronounce("구미"), "gumi");
+        assert_eq!(pronounce("영동"), "yeongdong");
+        assert_eq!(pronounce("옥천"), "okcheon");
+        assert_eq!(pronounce("합덕"), "hapdeok");
+        assert_eq!(pronounce("호법"), "hobeop");
+        assert_eq!(pronounce("월곶"), "wolgot");
+        assert_eq!(pronounce("벚꽃"), "beotkkot");
+        assert_eq!(pronounce("한밭"), "hanbat");
+        assert_eq!(pronounce("구리"), "guri");
+        assert_eq!(pronounce("칠곡"), "chilgok");
+        assert_eq!(pronounce("임실"), "imsil");
+        assert_eq!(pronounce("울릉"), "ulleung");
+        assert_eq!(pronounce("광희문"), "gwanghuimun");
+    }
+
+    #[test]
+    fn pronunciation_liaison() {
+        assert_eq!(pronounce("백암"), "baegam");
+        assert_eq!(pronounce("설악"), "seorak");
+        assert_eq!(pronounce("닭이"), "dalgi");
+        assert_eq!(pronounce("강아지"), "gangaji");
+        assert_eq!(pronounce("좋아"), "joa");
+    }
+
+    #[test]
+    fn pronunciation_assimilation() {
+        assert_eq!(pronounce("백마"), "baengma");
+        assert_eq!(pronounce("광한루"), "gwanghallu");
+        assert_eq!(pronounce("종로"), "jongno");
+        assert_eq!(pronounce("왕십리"), "wangsimni");
+        assert_eq!(pronounce("별내"), "byeollae");
+        assert_eq!(pronounce("신라"), "silla");
+        assert_eq!(pronounce("대관령"), "daegwallyeong");
+    }
+
+    #[test]
+    fn pronunciation_palatalization() {
+        assert_eq!(pronounce("해돋이"), "haedoji");
+        assert_eq!(pronounce("같이"), "gachi");
+        assert_eq!(pronounce("굳히다"), "guchida");
+    }
+
+    #[test]
+    fn pronunciation_aspiration() {
+        assert_eq!(pronounce("좋고"), "joko");
+        assert_eq!(pronounce("놓다"), "nota");
+        assert_eq!(pronounce("잡혀"), "japyeo");
+        assert_eq!(pronounce("낳지"), "nachi");
+    }
+
+    #[test]
+    fn pronunciation_words() {
+        assert_eq!(pronounce("서울 특별시"), "seoul teukbyeolsi");
+        assert_eq!(pronounce("한국어, Korean!"), "hangugeo, Korean!");
+    }
+
+    #[test]
+    fn transliteration() {
+        assert_eq!(transliterate("집"), "jib");
+        assert_eq!(transliterate("짚"), "jip");
+        assert_eq!(transliterate("밖"), "bakk");
+        assert_eq!(transliterate("값"), "gabs");
+        assert_eq!(transliterate("붓꽃"), "buskkoch");
+        assert_eq!(transliterate("먹는"), "meogneun");
+        assert_eq!(transliterate("독립"), "doglib");
+        assert_eq!(transliterate("문리"), "munli");
+        assert_eq!(transliterate("물엿"), "mul-yeos");
+        assert_eq!(transliterate("굳이"), "gud-i");
+        assert_eq!(transliterate("좋다"), "johda");
+        assert_eq!(transliterate("가곡"), "gagog");
+        assert_eq!(transliterate("조랑말"), "jolangmal");
+        assert_eq!(transliterate("없었습니다"), "eobs-eoss-seubnida");
+        assert_eq!(transliterate("가까"), "gakka");
+        // gakka would read as 가까
+        assert_eq!(transliterate("갘카"), "gak-ka");
+        assert_eq!(transliterate("한국"), "hangug");
+    }
+
+    #[test]
+    fn name() {
+        assert_eq!(RevisedRomanization::name("홍", "길동"), "Hong Gildong");
+        assert_eq!(RevisedRomanization::name("한", "복남"), "Han Boknam");
+        assert_eq!(RevisedRomanization::name("송", "나리"), "Song Nari");
+    }
+}
@@ -0,0 +1,24 @@
+use super::*;
+use crate::pronunciation::assimilate;
+pub(crate) use crate::pronunciation::neutralize;
+
+/// Applies the sound changes between every pair of adjacent syllables, except tensification.
+///
+/// When `aspirate_obstruents` is false, ㄱ, ㄷ and ㅂ followed by ㅎ are kept apart, e.g. 북한.
+pub(crate) fn assimilate_tokens(tokens: &mut [Token], aspirate_obstruents: bool) {
+    for index in 1..tokens.len() {
+        let (before, after) = tokens.split_at_mut(index);
+        if let (
+            Some(Token::Syllable(_, _, jongseong)),
+            Some(Token::Syllable(choseong, jungseong, _)),
+        ) = (before.last_mut(), after.first_mut())
+        {
+            if let Some(last) = jongseong.clone() {
+                let (next_jongseong, next_choseong) =
+                    assimilate(&last, choseong, jungseong, aspirate_obstruents);
+                *jongseong = next_jongseong;
+                *choseong = next_choseong;
+            }
+        }
+    }
+}
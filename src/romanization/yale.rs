@@ -0,0 +1,187 @@
+use super::*;
+use crate::characters::*;
+
+/// Romanizes letter by letter, without reflecting sound changes.
+///
+/// See:
+/// * https://en.wikipedia.org/wiki/Yale_romanization_of_Korean
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct YaleRomanization;
+
+pub(crate) const YALE_CHOSEONG: [&str; 18] = [
+    "k", "kk", "n", "t", "tt", "l", "m", "p", "pp", "s", "ss", "c", "cc", "ch", "kh", "th", "ph",
+    "h",
+];
+
+pub(crate) const YALE_JONGSEONG: [&str; 28] = [
+    "", "k", "kk", "ks", "n", "nc", "nh", "t", "l", "lk", "lm", "lp", "ls", "lth", "lph", "lh",
+    "m", "p", "ps", "s", "ss", "ng", "c", "ch", "kh", "th", "ph", "h",
+];
+
+fn choseong_letters(character: &ChoseongCharacter) -> &'static str {
+    match character {
+        ChoseongCharacter::Giyeok => "k",
+        ChoseongCharacter::SsangGiyeok => "kk",
+        ChoseongCharacter::Nieun => "n",
+        ChoseongCharacter::Digeut => "t",
+        ChoseongCharacter::SsangDigeut => "tt",
+        ChoseongCharacter::Rieul => "l",
+        ChoseongCharacter::Mieum => "m",
+        ChoseongCharacter::Bieup => "p",
+        ChoseongCharacter::SsangBieup => "pp",
+        ChoseongCharacter::Siot => "s",
+        ChoseongCharacter::SsangSiot => "ss",
+        ChoseongCharacter::Ieung => "",
+        ChoseongCharacter::Jieut => "c",
+        ChoseongCharacter::SsangJieut => "cc",
+        ChoseongCharacter::Chieut => "ch",
+        ChoseongCharacter::Kiyeok => "kh",
+        ChoseongCharacter::Tieut => "th",
+        ChoseongCharacter::Pieup => "ph",
+        ChoseongCharacter::Hieuh => "h",
+    }
+}
+
+/// ㅜ is written without `w` after ㅁ, ㅂ, ㅃ and ㅍ, e.g. 부 → pu.
+fn jungseong_letters(character: &JungseongCharacter, choseong: &ChoseongCharacter) -> &'static str {
+    let labial = matches!(
+        choseong,
+        ChoseongCharacter::Mieum
+            | ChoseongCharacter::Bieup
+            | ChoseongCharacter::SsangBieup
+            | ChoseongCharacter::Pieup
+    );
+
+    match character {
+        JungseongCharacter::A => "a",
+        JungseongCharacter::AE => "ay",
+        JungseongCharacter::YA => "ya",
+        JungseongCharacter::YAE => "yay",
+        JungseongCharacter::EO => "e",
+        JungseongCharacter::E => "ey",
+        JungseongCharacter::YEO => "ye",
+        JungseongCharacter::YE => "yey",
+        JungseongCharacter::O => "o",
+        JungseongCharacter::WA => "wa",
+        JungseongCharacter::WAE => "way",
+        JungseongCharacter::OE => "oy",
+        JungseongCharacter::YO => "yo",
+        JungseongCharacter::U if labial => "u",
+        JungseongCharacter::U => "wu",
+        JungseongCharacter::WEO => "we",
+        JungseongCharacter::WE => "wey",
+        JungseongCharacter::WI => "wi",
+        JungseongCharacter::YU => "yu",
+        JungseongCharacter::EU => "u",
+        JungseongCharacter::YI => "uy",
+        JungseongCharacter::I => "i",
+    }
+}
+
+fn jongseong_letters(character: &JongseongCharacter) -> &'static str {
+    match character {
+        JongseongCharacter::Giyeok => "k",
+        JongseongCharacter::SsangGiyeok => "kk",
+        JongseongCharacter::GiyeokSiot => "ks",
+        JongseongCharacter::Nieun => "n",
+        JongseongCharacter::NieunJieut => "nc",
+        JongseongCharacter::NieunHieuh => "nh",
+        JongseongCharacter::Digeut => "t",
+        JongseongCharacter::Rieul => "l",
+        JongseongCharacter::RieulGiyeok => "lk",
+        JongseongCharacter::RieulMieum => "lm",
+        JongseongCharacter::RieulBieup => "lp",
+        JongseongCharacter::RieulSiot => "ls",
+        JongseongCharacter::RieulTieut => "lth",
+        JongseongCharacter::RieulPieup => "lph",
+        JongseongCharacter::RieulHieuh => "lh",
+        JongseongCharacter::Mieum => "m",
+        JongseongCharacter::Bieup => "p",
+        JongseongCharacter::BieupSiot => "ps",
+        JongseongCharacter::Siot => "s",
+        JongseongCharacter::SsangSiot => "ss",
+        JongseongCharacter::Ieung => "ng",
+        JongseongCharacter::Jieut => "c",
+        JongseongCharacter::Chieut => "ch",
+        JongseongCharacter::Kieuk => "kh",
+        JongseongCharacter::Tieut => "th",
+        JongseongCharacter::Pieup => "ph",
+        JongseongCharacter::Hieuh => "h",
+    }
+}
+
+impl YaleRomanization {
+    pub fn new() -> Self {
+        YaleRomanization
+    }
+}
+
+impl Romanizer for YaleRomanization {
+    /// Syllables are separated with `.` where the letters would be ambiguous, e.g. 북한 → puk.han.
+    fn romanize(&self, text: &str) -> String {
+        let mut romanized = String::new();
+        let mut previous: Option<&str> = None;
+
+        for token in tokenize(text) {
+            match token {
+                Token::Syllable(choseong, jungseong, jongseong) => {
+                    let letters = choseong_letters(&choseong);
+                    if let Some(previous) = previous {
+                        if is_ambiguous(previous, letters, &YALE_JONGSEONG, &YALE_CHOSEONG) {
+                            romanized.push('.');
+                        }
+                    }
+                    romanized.push_str(letters);
+                    romanized.push_str(jungseong_letters(&jungseong, &choseong));
+
+                    let letters = jongseong.as_ref().map_or("", jongseong_letters);
+                    romanized.push_str(letters);
+                    previous = Some(letters);
+                }
+                Token::Other(character) => {
+                    romanized.push(character);
+                    previous = None;
+                }
+            }
+        }
+
+        romanized
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn romanize(text: &str) -> String {
+        YaleRomanization::new().romanize(text)
+    }
+
+    #[test]
+    fn letters() {
+        assert_eq!(romanize("한국"), "hankwuk");
+        assert_eq!(romanize("서울"), "sewul");
+        assert_eq!(romanize("부산"), "pusan");
+        assert_eq!(romanize("김치"), "kimchi");
+        assert_eq!(romanize("어머니"), "emeni");
+        assert_eq!(romanize("의사"), "uysa");
+        assert_eq!(romanize("고마워요"), "komaweyo");
+    }
+
+    #[test]
+    fn morphophonemic() {
+        assert_eq!(romanize("값"), "kaps");
+        assert_eq!(romanize("닭이"), "talk.i");
+        assert_eq!(romanize("달기"), "talki");
+        assert_eq!(romanize("좋다"), "cohta");
+        assert_eq!(romanize("없었습니다"), "eps.ess.supnita");
+    }
+
+    #[test]
+    fn separator() {
+        assert_eq!(romanize("북한"), "puk.han");
+        assert_eq!(romanize("부칸"), "pu.khan");
+        assert_eq!(romanize("한인"), "han.in");
+        assert_eq!(romanize("한국, Korea"), "hankwuk, Korea");
+    }
+}
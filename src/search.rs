@@ -0,0 +1,286 @@
+use crate::characters::*;
+use crate::choseong::*;
+use crate::syllable::*;
+use std::convert::TryFrom;
+use std::ops::Range;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Pattern {
+    /// Matches any character starting with the choseong.
+    Choseong(ChoseongCharacter),
+    Character(char),
+}
+
+impl Pattern {
+    fn matches(&self, character: char) -> bool {
+        match self {
+            Pattern::Choseong(choseong) => choseong_of(character).as_ref() == Some(choseong),
+            Pattern::Character(query) => *query == character,
+        }
+    }
+}
+
+/// The choseong of a syllable, or a choseong jamo itself.
+fn choseong_of(character: char) -> Option<ChoseongCharacter> {
+    if !character.is_hangeul() || !character.has_choseong() {
+        return None;
+    }
+    Choseong::try_from(character)
+        .ok()
+        .map(ChoseongCharacter::from)
+}
+
+/// Searches by choseong (초성 검색), e.g. `ㄱㄴㄷ` or `가ㄴ다` matches 가나다.
+///
+/// Choseong jamo in the query match any syllable with that choseong,
+/// other characters have to match exactly.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChoseongMatcher {
+    patterns: Vec<Pattern>,
+}
+
+impl ChoseongMatcher {
+    pub fn new(query: &str) -> Self {
+        let patterns = query
+            .chars()
+            .map(|character| {
+                if character.is_jamo() {
+                    if let Ok(choseong) = Choseong::try_from(character) {
+                        return Pattern::Choseong(choseong.into());
+                    }
+                }
+                Pattern::Character(character)
+            })
+            .collect();
+
+        ChoseongMatcher { patterns }
+    }
+
+    pub fn is_match(&self, target: &str) -> bool {
+        self.find(target).is_some()
+    }
+
+    /// The byte range of the first match in `target`.
+    pub fn find(&self, target: &str) -> Option<Range<usize>> {
+        self.find_from(target, 0)
+    }
+
+    /// The byte ranges of all non-overlapping matches in `target`.
+    pub fn find_all(&self, target: &str) -> Vec<Range<usize>> {
+        let mut matches = vec![];
+        let mut start = 0;
+
+        while let Some(range) = self.find_from(target, start) {
+            start = range.end;
+            matches.push(range);
+        }
+
+        matches
+    }
+
+    fn find_from(&self, target: &str, start: usize) -> Option<Range<usize>> {
+        if self.patterns.is_empty() || start > target.len() {
+            return None;
+        }
+
+        target[start..]
+            .char_indices()
+            .map(|(index, _)| start + index)
+            .find_map(|index| {
+                let mut characters = target[index..].char_indices();
+                let mut end = index;
+                for pattern in &self.patterns {
+                    match characters.next() {
+                        Some((offset, character)) if pattern.matches(character) => {
+                            end = index + offset + character.len_utf8();
+                        }
+                        _ => return None,
+                    }
+                }
+                Some(index..end)
+            })
+    }
+}
+
+type Jamo = (
+    ChoseongCharacter,
+    JungseongCharacter,
+    Option<JongseongCharacter>,
+);
+
+fn to_jamo(character: char) -> Option<Jamo> {
+    Syllable::try_from(character).ok().map(Jamo::from)
+}
+
+/// Matches the start of texts while the last syllable of the query may still be typed,
+/// e.g. `갑` matches 가방 as the user may be typing 가 + ㅂ.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PrefixMatcher {
+    query: Vec<char>,
+}
+
+impl PrefixMatcher {
+    pub fn new(query: &str) -> Self {
+        PrefixMatcher {
+            query: query.chars().collect(),
+        }
+    }
+
+    pub fn is_match(&self, target: &str) -> bool {
+        self.find(target).is_some()
+    }
+
+    /// The byte range of `target` matched by the query, always starting at 0.
+    pub fn find(&self, target: &str) -> Option<Range<usize>> {
+        let (last, typed) = match self.query.split_last() {
+            Some(split) => split,
+            None => return Some(0..0),
+        };
+
+        let mut characters = target.char_indices();
+        for query in typed {
+            match characters.next() {
+                Some((_, character)) if character == *query => {}
+                _ => return None,
+            }
+        }
+
+        let (index, character) = characters.next()?;
+        let end = index + character.len_utf8();
+        if character == *last {
+            return Some(0..end);
+        }
+        if last.is_jamo() {
+            let choseong = Choseong::try_from(*last).ok().map(ChoseongCharacter::from);
+            return match choseong {
+                Some(_) if choseong == choseong_of(character) => Some(0..end),
+                _ => None,
+            };
+        }
+
+        let (choseong, jungseong, jongseong) = to_jamo(*last)?;
+        let (target_choseong, target_jungseong, target_jongseong) = to_jamo(character)?;
+        if choseong != target_choseong {
+            return None;
+        }
+        if jungseong != target_jungseong {
+            // A vowel that may still become a compound vowel, e.g. 고 → 과.
+            let compound = target_jungseong.split().map(|(first, _)| first);
+            return match jongseong {
+                None if compound == Some(jungseong) => Some(0..end),
+                _ => None,
+            };
+        }
+
+        let jongseong = match jongseong {
+            Some(jongseong) => jongseong,
+            None => return Some(0..end),
+        };
+        if let Some(target_jongseong) = &target_jongseong {
+            // A final that may still become a compound final, e.g. 갈 → 갉.
+            let compound = target_jongseong.split().map(|(first, _)| first);
+            if *target_jongseong == jongseong || compound == Some(jongseong.clone()) {
+                return Some(0..end);
+            }
+        }
+
+        // A final that may still move over to the next syllable, e.g. 갑 → 가방, 갉 → 갈가.
+        let (kept, moved) = match jongseong.split() {
+            Some((kept, moved)) => (Some(kept), moved),
+            None => (None, jongseong),
+        };
+        if target_jongseong != kept {
+            return None;
+        }
+        let (index, next) = characters.next()?;
+        match (moved.to_choseong(), choseong_of(next)) {
+            (Some(moved), Some(choseong)) if moved == choseong => Some(0..index + next.len_utf8()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn choseong_query() {
+        let matcher = ChoseongMatcher::new("ㄱㄴㄷ");
+        assert_eq!(matcher.is_match("가나다"), true);
+        assert_eq!(matcher.is_match("강남대로"), true);
+        assert_eq!(matcher.is_match("가다나"), false);
+        assert_eq!(matcher.find("서울 강남대로"), Some(7..16));
+        assert_eq!(matcher.find("ㄱㄴㄷ"), Some(0..9));
+    }
+
+    #[test]
+    fn mixed_query() {
+        let matcher = ChoseongMatcher::new("가ㄴ다");
+        assert_eq!(matcher.is_match("가나다"), true);
+        assert_eq!(matcher.is_match("가느다란"), true);
+        assert_eq!(matcher.is_match("고나다"), false);
+
+        let matcher = ChoseongMatcher::new("ㅎㄱ 2");
+        assert_eq!(matcher.find("한국 2호점"), Some(0..8));
+    }
+
+    #[test]
+    fn normal_jamo_query() {
+        let matcher = ChoseongMatcher::new("\u{1112}\u{1100}");
+        assert_eq!(matcher.find("한국"), Some(0..6));
+    }
+
+    #[test]
+    fn find_all() {
+        let matcher = ChoseongMatcher::new("ㄱㅅ");
+        assert_eq!(
+            matcher.find_all("감사 고생 가수"),
+            vec![0..6, 7..13, 14..20]
+        );
+        assert_eq!(ChoseongMatcher::new("").find_all("가"), vec![]);
+        assert_eq!(ChoseongMatcher::new("ㄱ").find_all(""), vec![]);
+    }
+
+    #[test]
+    fn prefix_typed() {
+        let matcher = PrefixMatcher::new("가방");
+        assert_eq!(matcher.find("가방끈"), Some(0..6));
+        assert_eq!(matcher.is_match("가발"), false);
+        assert_eq!(PrefixMatcher::new("").find("가"), Some(0..0));
+        assert_eq!(PrefixMatcher::new("가").is_match(""), false);
+    }
+
+    #[test]
+    fn prefix_typing_choseong() {
+        let matcher = PrefixMatcher::new("가ㅂ");
+        assert_eq!(matcher.find("가방"), Some(0..6));
+        assert_eq!(matcher.is_match("가수"), false);
+    }
+
+    #[test]
+    fn prefix_typing_jongseong() {
+        let matcher = PrefixMatcher::new("갑");
+        assert_eq!(matcher.find("가방"), Some(0..6));
+        assert_eq!(matcher.find("갑옷"), Some(0..3));
+        assert_eq!(matcher.find("값"), Some(0..3));
+        assert_eq!(matcher.is_match("가수"), false);
+        assert_eq!(matcher.is_match("감자"), false);
+
+        let matcher = PrefixMatcher::new("갉");
+        assert_eq!(matcher.find("갈가"), Some(0..6));
+        assert_eq!(matcher.is_match("갈나"), false);
+    }
+
+    #[test]
+    fn prefix_typing_jungseong() {
+        let matcher = PrefixMatcher::new("고");
+        assert_eq!(matcher.find("과일"), Some(0..3));
+        assert_eq!(matcher.find("괴물"), Some(0..3));
+        assert_eq!(matcher.is_match("교실"), false);
+
+        let matcher = PrefixMatcher::new("한구");
+        assert_eq!(matcher.find("한국어"), Some(0..6));
+        assert_eq!(matcher.find("한권"), Some(0..6));
+    }
+}
@@ -1,6 +1,7 @@
 use crate::characters::*;
 use crate::choseong::*;
 use crate::constants::*;
+use crate::error::*;
 use crate::jongseong::*;
 use crate::jungseong::*;
 use std::convert::{TryFrom, TryInto};
@@ -9,19 +10,19 @@ use std::convert::{TryFrom, TryInto};
 pub struct Syllable(u32);
 
 impl TryFrom<u32> for Syllable {
-    type Error = ();
+    type Error = HangulError;
 
     fn try_from(item: u32) -> Result<Self, Self::Error> {
         if item.is_syllable() {
             Ok(Syllable(item))
         } else {
-            Err(())
+            Err(HangulError::new(item, HangulCategory::Syllable))
         }
     }
 }
 
 impl TryFrom<char> for Syllable {
-    type Error = ();
+    type Error = HangulError;
 
     fn try_from(item: char) -> Result<Self, Self::Error> {
         (item as u32).try_into()
@@ -41,7 +42,7 @@ impl From<Syllable> for char {
 }
 
 impl TryFrom<(u32, u32, Option<u32>)> for Syllable {
-    type Error = ();
+    type Error = HangulError;
 
     fn try_from(item: (u32, u32, Option<u32>)) -> Result<Self, Self::Error> {
         let choseong = Choseong::try_from(item.0)?.to_composable();
@@ -56,7 +57,7 @@ impl TryFrom<(u32, u32, Option<u32>)> for Syllable {
 }
 
 impl TryFrom<(char, char, Option<char>)> for Syllable {
-    type Error = ();
+    type Error = HangulError;
 
     fn try_from(item: (char, char, Option<char>)) -> Result<Self, Self::Error> {
         Syllable::try_from((item.0 as u32, item.1 as u32, item.2.map(|code| code as u32)))
@@ -64,7 +65,7 @@ impl TryFrom<(char, char, Option<char>)> for Syllable {
 }
 
 impl TryFrom<(Choseong, Jungseong, Option<Jongseong>)> for Syllable {
-    type Error = ();
+    type Error = HangulError;
 
     fn try_from(item: (Choseong, Jungseong, Option<Jongseong>)) -> Result<Self, Self::Error> {
         let choseong = item.0.to_composable();
@@ -112,22 +113,69 @@ impl From<Syllable> for (char, char, Option<char>) {
 impl From<Syllable> for (Choseong, Jungseong, Option<Jongseong>) {
     fn from(item: Syllable) -> (Choseong, Jungseong, Option<Jongseong>) {
         let choseong: Choseong = match Choseong::try_from(item.0) {
-            Ok(character) => character.into(),
+            Ok(character) => character,
             Err(_) => unreachable!(),
         };
         let jungseong: Jungseong = match Jungseong::try_from(item.0) {
-            Ok(character) => character.into(),
+            Ok(character) => character,
             Err(_) => unreachable!(),
         };
-        let jongseong: Option<Jongseong> = match Jongseong::try_from(item.0) {
-            Ok(character) => Some(character.into()),
-            Err(_) => None,
-        };
+        let jongseong: Option<Jongseong> = Jongseong::try_from(item.0).ok();
 
         (choseong, jungseong, jongseong)
     }
 }
 
+impl
+    From<(
+        ChoseongCharacter,
+        JungseongCharacter,
+        Option<JongseongCharacter>,
+    )> for Syllable
+{
+    fn from(
+        item: (
+            ChoseongCharacter,
+            JungseongCharacter,
+            Option<JongseongCharacter>,
+        ),
+    ) -> Syllable {
+        let choseong = item.0.to_composable();
+        let jungseong = item.1.to_composable();
+        let jongseong = item
+            .2
+            .map(|character| character.to_composable())
+            .unwrap_or(0);
+
+        Syllable(choseong + jungseong + jongseong + HANGEUL_OFFSET)
+    }
+}
+
+impl From<Syllable>
+    for (
+        ChoseongCharacter,
+        JungseongCharacter,
+        Option<JongseongCharacter>,
+    )
+{
+    fn from(
+        item: Syllable,
+    ) -> (
+        ChoseongCharacter,
+        JungseongCharacter,
+        Option<JongseongCharacter>,
+    ) {
+        let (choseong, jungseong, jongseong): (Choseong, Jungseong, Option<Jongseong>) =
+            item.into();
+
+        (
+            choseong.into(),
+            jungseong.into(),
+            jongseong.map(JongseongCharacter::from),
+        )
+    }
+}
+
 pub trait SyllableInformation {
     fn is_hangeul(&self) -> bool;
     fn is_syllable(&self) -> bool;